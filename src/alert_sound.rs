@@ -0,0 +1,46 @@
+//! A short sound that is played when the cpu, memory or swap usage becomes critical
+//!
+//! Playing sounds needs the `rodio` crate, which is only included when the application
+//! is built with the `alert-sound` feature. Without it, no sound is played.
+
+use std::time::Duration;
+
+/// The minimum time between two alert sounds
+///
+/// This keeps a metric that goes back and forth around its threshold from
+/// playing the sound on every update.
+pub const ALERT_SOUND_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Plays the alert sound in the background
+///
+/// The sound is a short beep, so no sound file needs to be shipped with the application.
+/// Systems without an audio device are silently ignored.
+#[cfg(feature = "alert-sound")]
+pub fn play() {
+    use rodio::{source::SineWave, OutputStream, Sink, Source};
+
+    // The output stream has to be kept alive until the sound has finished,
+    // so it is played on its own thread to not block the user interface
+    std::thread::spawn(|| {
+        let Ok((_stream, handle)) = OutputStream::try_default() else {
+            return;
+        };
+
+        let Ok(sink) = Sink::try_new(&handle) else {
+            return;
+        };
+
+        sink.append(
+            SineWave::new(880.0)
+                .take_duration(Duration::from_millis(300))
+                .amplify(0.2),
+        );
+        sink.sleep_until_end();
+    });
+}
+
+/// Plays the alert sound in the background
+///
+/// The application was built without the `alert-sound` feature, so nothing is played.
+#[cfg(not(feature = "alert-sound"))]
+pub fn play() {}
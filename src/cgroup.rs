@@ -0,0 +1,137 @@
+//! The memory and cpu limits of the container the application runs in
+//!
+//! Inside a container, sysinfo reports the memory and cpus of the host, which can be
+//! far more than the container is allowed to use. The limits are read from the cgroup
+//! of the process instead. This is only supported on Linux, for both cgroup v1 and v2.
+//! Outside of a container, or on other platforms, no limits are reported.
+
+/// The limits of the container the application runs in
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub struct ContainerLimits {
+    /// The memory the container may use, in bytes, or `None` if it is not limited
+    pub memory_limit: Option<u64>,
+
+    /// The memory the container currently uses, in bytes
+    pub memory_usage: Option<u64>,
+
+    /// The number of cpus the container may use, or `None` if it is not limited
+    ///
+    /// This can be a fraction, for example 1.5 when the container may use one and a half cpus.
+    pub cpu_limit: Option<f64>,
+}
+
+/// Reads the limits of the container the application runs in
+///
+/// Returns `None` when not running in a container, or when the container has no limits.
+#[cfg(target_os = "linux")]
+pub fn read_container_limits() -> Option<ContainerLimits> {
+    if !is_in_container() {
+        return None;
+    }
+
+    let cgroup_path = read_cgroup_v2_path();
+
+    let limits = match &cgroup_path {
+        Some(path) => ContainerLimits {
+            memory_limit: read_value(&format!("{path}/memory.max")),
+            memory_usage: read_value(&format!("{path}/memory.current")),
+            cpu_limit: read_cpu_max(&format!("{path}/cpu.max")),
+        },
+        None => ContainerLimits {
+            memory_limit: read_value("/sys/fs/cgroup/memory/memory.limit_in_bytes"),
+            memory_usage: read_value("/sys/fs/cgroup/memory/memory.usage_in_bytes"),
+            cpu_limit: read_cfs_quota(),
+        },
+    };
+
+    // cgroup v1 reports no memory limit as a huge number instead of "max"
+    let limits = ContainerLimits {
+        memory_limit: limits.memory_limit.filter(|&limit| limit < u64::MAX / 2),
+        ..limits
+    };
+
+    if limits.memory_limit.is_none() && limits.cpu_limit.is_none() {
+        return None;
+    }
+
+    Some(limits)
+}
+
+/// Reads the limits of the container the application runs in
+///
+/// Containers are not supported on this platform, so `None` is returned.
+#[cfg(not(target_os = "linux"))]
+pub fn read_container_limits() -> Option<ContainerLimits> {
+    None
+}
+
+/// Returns whether the application runs in a container
+///
+/// Docker and Podman leave a marker file in the root of the container,
+/// and systemd-nspawn and LXC set the `container` environment variable.
+#[cfg(target_os = "linux")]
+fn is_in_container() -> bool {
+    std::path::Path::new("/.dockerenv").exists()
+        || std::path::Path::new("/run/.containerenv").exists()
+        || std::env::var_os("container").is_some()
+}
+
+/// Returns the directory of the process's cgroup, if the system uses cgroup v2
+///
+/// With cgroup v2, `/proc/self/cgroup` has a single line that looks like `0::/some/path`.
+/// Inside a container the path is usually just `/`.
+#[cfg(target_os = "linux")]
+fn read_cgroup_v2_path() -> Option<String> {
+    let cgroup = std::fs::read_to_string("/proc/self/cgroup").ok()?;
+    let path = cgroup.lines().find_map(|line| line.strip_prefix("0::"))?;
+
+    let directory = format!("/sys/fs/cgroup{}", path.trim_end_matches('/'));
+
+    // The path is only visible when the cgroup namespace matches the mount
+    if std::path::Path::new(&directory).join("memory.max").exists() {
+        Some(directory)
+    } else if std::path::Path::new("/sys/fs/cgroup/memory.max").exists() {
+        Some("/sys/fs/cgroup".to_string())
+    } else {
+        None
+    }
+}
+
+/// Reads a file containing a single number
+///
+/// Returns `None` if the file could not be read, or contains "max" for no limit.
+#[cfg(target_os = "linux")]
+fn read_value(path: &str) -> Option<u64> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Reads the cpu limit from the `cpu.max` file of cgroup v2
+///
+/// The file looks like `150000 100000`, the time the cgroup may run
+/// in each period, and the length of the period, in microseconds.
+/// The quota is "max" when the cpus are not limited.
+#[cfg(target_os = "linux")]
+fn read_cpu_max(path: &str) -> Option<f64> {
+    let cpu_max = std::fs::read_to_string(path).ok()?;
+    let mut fields = cpu_max.split_whitespace();
+
+    let quota: f64 = fields.next()?.parse().ok()?;
+    let period: f64 = fields.next()?.parse().ok()?;
+
+    (period > 0.0).then(|| quota / period)
+}
+
+/// Reads the cpu limit from the cfs quota and period of cgroup v1
+///
+/// The quota is -1 when the cpus are not limited.
+#[cfg(target_os = "linux")]
+fn read_cfs_quota() -> Option<f64> {
+    let read =
+        |path: &str| -> Option<f64> { std::fs::read_to_string(path).ok()?.trim().parse().ok() };
+
+    let quota = read("/sys/fs/cgroup/cpu/cpu.cfs_quota_us")?;
+    let period = read("/sys/fs/cgroup/cpu/cpu.cfs_period_us")?;
+
+    (quota > 0.0 && period > 0.0).then(|| quota / period)
+}
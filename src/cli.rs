@@ -0,0 +1,44 @@
+//! Parsing of the command line arguments
+//!
+//! The arguments are parsed by hand, as there are only a few of them.
+
+use std::path::PathBuf;
+
+/// The options the application was launched with
+#[derive(Debug, Clone, Default)]
+pub struct LaunchOptions {
+    /// Whether to open the minimal meter window instead of the full window
+    ///
+    /// Set with the `--meter` argument.
+    pub meter: bool,
+
+    /// The path of the config file to use instead of the default one
+    ///
+    /// Set with the `--config <path>` argument.
+    pub config_path: Option<PathBuf>,
+}
+
+impl LaunchOptions {
+    /// Parses the launch options from the command line arguments
+    ///
+    /// Unknown arguments are reported on stderr and otherwise ignored.
+    pub fn from_args() -> Self {
+        let mut options = Self::default();
+
+        // The first argument is the path of the executable
+        let mut args = std::env::args_os().skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.to_str() {
+                Some("--meter") => options.meter = true,
+                Some("--config") => match args.next() {
+                    Some(path) => options.config_path = Some(PathBuf::from(path)),
+                    None => eprintln!("Missing path after --config"),
+                },
+                _ => eprintln!("Ignoring unknown argument: {}", arg.to_string_lossy()),
+            }
+        }
+
+        options
+    }
+}
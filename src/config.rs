@@ -1,22 +1,689 @@
-use std::io;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
 
 use directories_next::ProjectDirs;
 use serde::{Deserialize, Serialize};
 
+use crate::thresholds::Metric;
+
+/// The environment variable that can be set to the path of the config file
+pub const CONFIG_PATH_ENV_VAR: &str = "ICY_SYSMONITOR_CONFIG";
+
+/// The path of the config file given on the command line, if any
+///
+/// This is set once at startup, before the config is loaded.
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Writes a file by writing a temporary file next to it and renaming it over the original
+///
+/// The rename replaces the file in one step, so a crash while writing leaves either
+/// the old or the new contents, never a half-written file.
+pub fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let temporary_path = get_temporary_path(path);
+
+    std::fs::write(&temporary_path, contents)?;
+    std::fs::rename(&temporary_path, path)
+}
+
+/// Writes a file like `write_atomically`, without blocking the calling thread
+pub async fn write_atomically_async(path: &Path, contents: &str) -> io::Result<()> {
+    let temporary_path = get_temporary_path(path);
+
+    tokio::fs::write(&temporary_path, contents).await?;
+    tokio::fs::rename(&temporary_path, path).await
+}
+
+/// Returns the path of the temporary file that is written before it replaces `path`
+fn get_temporary_path(path: &Path) -> PathBuf {
+    let mut temporary_name = path.file_name().unwrap_or_default().to_os_string();
+    temporary_name.push(".tmp");
+
+    path.with_file_name(temporary_name)
+}
+
 /// Struct that stores the configuration for the application.
+///
+/// Missing fields are filled in with their default values, so config files
+/// written by older versions of the application can still be loaded.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// The interval in milliseconds between each update.
     ///
     /// This is the time between each call to the `update` function.
     pub update_interval: u64,
+
+    /// How the page navigation is laid out in the main window.
+    pub navigation: NavigationLayout,
+
+    /// Whether to show the large title at the top of the main window.
+    ///
+    /// Hiding it saves vertical space on small windows.
+    pub show_title: bool,
+
+    /// Whether to show the current cpu and memory usage in the window title.
+    ///
+    /// This makes the usage readable from the taskbar or window list.
+    pub live_title: bool,
+
+    /// Whether to show the cpu model name below the title.
+    pub show_cpu_brand: bool,
+
+    /// Whether to briefly highlight a row when its value changes significantly.
+    pub highlight_changes: bool,
+
+    /// How much a percentage has to change between two updates to be highlighted,
+    /// in percentage points.
+    pub highlight_delta: f32,
+
+    /// The interval in milliseconds between each refresh of the temperature sensors.
+    ///
+    /// The sensors change slowly and can be expensive to read, so they are
+    /// refreshed less often than the rest of the system info.
+    pub temperature_interval: u64,
+
+    /// The color scheme of the progress bars.
+    pub bar_palette: BarPalette,
+
+    /// Whether to update less often while the main window is not focused.
+    ///
+    /// The update interval is multiplied by `unfocused_interval_factor` until the window is focused again.
+    pub throttle_when_unfocused: bool,
+
+    /// How many times longer the update interval is while the main window is not focused.
+    pub unfocused_interval_factor: u64,
+
+    /// Whether the main window shows the compact view, with only the cpu and memory bars.
+    ///
+    /// This is toggled with F2, and the last state is restored on the next launch.
+    pub compact_mode: bool,
+
+    /// Whether to show icons next to the page names in the navigation.
+    ///
+    /// The icons come from a bundled icon font. Turning them off helps on systems where the
+    /// font does not render, and the tabs would otherwise show empty boxes.
+    pub navigation_icons: bool,
+
+    /// How the cpu usage percentages are rounded to two decimal places.
+    pub percent_rounding: PercentRounding,
+
+    /// Whether to show the temperature of each core next to its usage, where the sensors allow it.
+    ///
+    /// The sensors are matched to the cores by their labels, which only works on some platforms.
+    pub show_core_temperatures: bool,
+
+    /// The unit that memory and disk sizes are shown in.
+    ///
+    /// With the automatic unit, the unit is picked for each value, so it can change as the value grows.
+    pub size_unit: SizeUnit,
+
+    /// Whether to collapse the idle cpus into a single row on the cpu panel.
+    ///
+    /// This declutters the panel on machines with many mostly idle cores.
+    pub collapse_idle_cores: bool,
+
+    /// The usage in percent below which a cpu is considered idle, when collapsing the idle cpus.
+    pub idle_core_threshold: f32,
+
+    /// Paths whose free space is shown on the overview page.
+    ///
+    /// The free space is that of the disk the path is on, so this can be used to watch
+    /// a data partition or a network mount by its path.
+    pub watched_paths: Vec<PathBuf>,
+
+    /// Whether to show network rates in bits per second instead of bytes per second.
+    pub network_bits: bool,
+
+    /// How the total cpu usage and the memory usage are drawn on the overview page.
+    pub usage_display: UsageDisplay,
+
+    /// The path of a font file to use for all text, instead of the default font.
+    ///
+    /// A monospace font keeps the columns of numbers aligned. The default font is used
+    /// if the file cannot be read. Changes take effect on the next launch.
+    pub font: Option<PathBuf>,
+
+    /// Whether to play a short sound when the cpu, memory or swap usage becomes critical
+    ///
+    /// The usage is critical where the progress bars turn red, set in `bar_colors`.
+    /// Only has an effect when the application is built with the `alert-sound` feature.
+    pub alert_sound: bool,
+
+    /// Whether to split the bar of each cpu into the time spent in user and system mode
+    ///
+    /// This is only supported on Linux. On other platforms the total usage is shown instead.
+    pub stacked_cpu_bars: bool,
+
+    /// Whether to show a one-line summary of the cpu, memory and network usage next to the navigation
+    ///
+    /// The summary stays visible on every page.
+    pub header_summary: bool,
+
+    /// How the cpus are laid out on the home page
+    pub cpu_layout: CpuLayout,
+
+    /// The width of the cpu and memory bars on the home page, in pixels
+    ///
+    /// The bars fill the width of the window when this is not set.
+    pub bar_width: Option<u16>,
+
+    /// The name of the profile the config was last loaded from
+    ///
+    /// This is `None` if no profile has been loaded.
+    pub current_profile: Option<String>,
+
+    /// Whether to show the load average, with a graph of the last five minutes
+    ///
+    /// This is only supported on Unix-like platforms.
+    pub show_load_average: bool,
+
+    /// Whether to show the available memory as a separate bar below the memory usage
+    pub show_available_memory: bool,
+
+    /// Where the tab bar is placed, when the tab bar navigation is used
+    pub tab_bar_position: TabBarPosition,
+
+    /// Whether to ask for confirmation before closing the main window
+    ///
+    /// The compact view and the meter window are too small for the prompt, so they always close right away.
+    pub confirm_on_quit: bool,
+
+    /// How the memory usage bar on the home page is drawn
+    pub memory_bar: MemoryBarMode,
+
+    /// Whether to show a chart of the usage of each cpu over the last minute, stacked on top of each other
+    ///
+    /// Above 16 cpus the bands get too thin to read, so the total usage is shown instead.
+    pub show_core_chart: bool,
+
+    /// Whether to do less work while the system is busy
+    ///
+    /// While the average cpu usage of the last minute is above 90%, the updates are half
+    /// as frequent and the charts are paused, so that the monitor adds little load
+    /// when the system is already stressed.
+    pub reduce_work_when_busy: bool,
+
+    /// Whether the cpu bars move smoothly to each new value, instead of jumping to it
+    ///
+    /// The bars are not animated while the monitor is doing less work because the system is busy.
+    pub animate_bars: bool,
+
+    /// Whether each cpu bar has a marker at its recent peak, which falls back slowly
+    ///
+    /// This keeps short bursts visible after the usage has dropped again, like the peak hold of an audio meter.
+    /// The marker is only drawn on the plain cpu bars, not on the stacked bars or the tiles.
+    pub cpu_peak_hold: bool,
+
+    /// The share of the home page given to the cpu section when the memory section is shown below it.
+    ///
+    /// This is a number between 0 and 1, which is set by dragging the divider between the sections.
+    /// The sections are split evenly when it is not set.
+    pub home_split_ratio: Option<f32>,
+
+    /// The values above which a warning badge is shown next to a metric.
+    pub thresholds: Thresholds,
+
+    /// The usage percentages at which the progress bars change color.
+    pub bar_colors: BarColorThresholds,
+
+    /// The settings for periodically writing snapshots of the system info to disk.
+    pub scheduled_export: ScheduledExport,
+
+    /// Which categories of system info are collected and shown.
+    pub metrics: EnabledMetrics,
+
+    /// Metrics read from external commands, shown as extra tiles on the overview page
+    ///
+    /// Each command is run on every update, and should print a single number.
+    pub custom_metrics: Vec<CustomMetric>,
+}
+
+/// The layout of the page navigation in the main window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NavigationLayout {
+    /// A tab bar below the title, at the top of the window
+    TabBar,
+
+    /// A vertical sidebar on the left-hand side of the window
+    ///
+    /// This suits wider windows and leaves more vertical space for the pages.
+    Sidebar,
+}
+
+/// The color schemes of the progress bars
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarPalette {
+    /// The accent color of the theme, regardless of the value
+    Classic,
+
+    /// Green, yellow or red, depending on the bar color thresholds
+    TrafficLight,
+
+    /// The text color of the theme, regardless of the value
+    Monochrome,
+
+    /// A smooth blend from green through yellow to red as the value grows
+    Gradient,
+}
+
+impl BarPalette {
+    /// Every palette, in the order they are listed in the settings page
+    pub const ALL: [BarPalette; 4] = [
+        BarPalette::Classic,
+        BarPalette::TrafficLight,
+        BarPalette::Monochrome,
+        BarPalette::Gradient,
+    ];
+}
+
+impl std::fmt::Display for BarPalette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BarPalette::Classic => "Classic",
+            BarPalette::TrafficLight => "Traffic-light",
+            BarPalette::Monochrome => "Monochrome",
+            BarPalette::Gradient => "Gradient",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// How the memory usage bar is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryBarMode {
+    /// Only the memory, with a marker at the memory warning threshold
+    Memory,
+
+    /// The memory followed by the swap in a single bar, to show the total committed memory
+    MemoryAndSwap,
+}
+
+/// Where the tab bar is placed in the main window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TabBarPosition {
+    /// Below the title, above the page
+    Top,
+
+    /// Below the page, at the bottom of the window
+    Bottom,
+
+    /// On the same line as the title
+    BesideTitle,
+}
+
+impl TabBarPosition {
+    /// Every position, in the order they are listed in the settings page
+    pub const ALL: [TabBarPosition; 3] = [
+        TabBarPosition::Top,
+        TabBarPosition::Bottom,
+        TabBarPosition::BesideTitle,
+    ];
+}
+
+impl std::fmt::Display for TabBarPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TabBarPosition::Top => "Top",
+            TabBarPosition::Bottom => "Bottom",
+            TabBarPosition::BesideTitle => "Beside the title",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// How a percentage is rounded to the number of decimal places that is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PercentRounding {
+    /// Round to the nearest value, so 99.999% is shown as 100.00%
+    Round,
+
+    /// Round down, so 99.999% is shown as 99.99%
+    ///
+    /// A value is then only shown as 100% when it actually is 100%.
+    Floor,
+}
+
+impl PercentRounding {
+    /// Rounds a percentage to the given number of decimal places
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// PercentRounding::Round.apply(99.999, 2); // 100.0
+    /// PercentRounding::Floor.apply(99.999, 2); // 99.99
+    /// ```
+    pub fn apply(self, percentage: f32, decimals: i32) -> f32 {
+        // Values such as 0.53 are stored as slightly less than that in an f32,
+        // so the scaled value is nudged up before flooring, to not show them as 0.52
+        const FLOOR_EPSILON: f64 = 1e-3;
+
+        let scale = 10f64.powi(decimals);
+        let scaled = percentage as f64 * scale;
+
+        let rounded = match self {
+            PercentRounding::Round => scaled.round(),
+            PercentRounding::Floor => (scaled + FLOOR_EPSILON).floor(),
+        };
+
+        (rounded / scale) as f32
+    }
+}
+
+/// How a usage percentage is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageDisplay {
+    /// A horizontal progress bar
+    Bar,
+
+    /// A circular gauge with a needle
+    Gauge,
+}
+
+/// How the cpus are laid out on the home page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CpuLayout {
+    /// One full-width row per cpu
+    Rows,
+
+    /// Small fixed-width tiles that wrap onto new lines, to fit more cpus on screen
+    Wrapped,
+}
+
+/// The unit that memory and disk sizes are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizeUnit {
+    /// Pick the unit that suits each value
+    Auto,
+
+    /// Always show mebibytes
+    Mib,
+
+    /// Always show gibibytes
+    Gib,
+
+    /// Always show tebibytes
+    Tib,
+}
+
+impl SizeUnit {
+    /// Formats a size in bytes in this unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// SizeUnit::Auto.format(1_073_741_824); // "1.1 GB"
+    /// SizeUnit::Mib.format(1_073_741_824); // "1024.00 MiB"
+    /// SizeUnit::Gib.format(1_073_741_824); // "1.00 GiB"
+    /// ```
+    pub fn format(self, bytes: u64) -> String {
+        let (divisor, suffix) = match self {
+            SizeUnit::Auto => return bytesize::ByteSize(bytes).to_string(),
+            SizeUnit::Mib => (1u64 << 20, "MiB"),
+            SizeUnit::Gib => (1u64 << 30, "GiB"),
+            SizeUnit::Tib => (1u64 << 40, "TiB"),
+        };
+
+        format!("{:.2} {suffix}", bytes as f64 / divisor as f64)
+    }
+}
+
+/// The warning thresholds of the metrics
+///
+/// A warning badge is shown next to a metric when its value exceeds its threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Thresholds {
+    /// The total cpu usage threshold in percent
+    pub cpu: f32,
+
+    /// The memory usage threshold in percent
+    pub memory: f32,
+
+    /// The swap usage threshold in percent
+    pub swap: f32,
+
+    /// The temperature threshold in degrees celsius
+    pub temperature: f32,
+}
+
+impl Thresholds {
+    /// Returns the threshold of a metric
+    pub fn get(&self, metric: Metric) -> f32 {
+        match metric {
+            Metric::Cpu => self.cpu,
+            Metric::Memory => self.memory,
+            Metric::Swap => self.swap,
+            Metric::Temperature => self.temperature,
+        }
+    }
+}
+
+/// The usage percentages at which the progress bars turn yellow and red
+///
+/// Below the yellow threshold the bars are green.
+/// The yellow threshold is always lower than the red threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BarColorThresholds {
+    /// The usage percentage at which the bars turn yellow
+    pub yellow: f32,
+
+    /// The usage percentage at which the bars turn red
+    pub red: f32,
+}
+
+impl Default for BarColorThresholds {
+    /// Create the color thresholds with the default values.
+    fn default() -> Self {
+        Self {
+            yellow: 60.0,
+            red: 85.0,
+        }
+    }
+}
+
+/// The settings for periodically writing snapshots of the system info to disk
+///
+/// Each snapshot is written to a new CSV file with a timestamped name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduledExport {
+    /// Whether snapshots are written at all
+    pub enabled: bool,
+
+    /// Whether a snapshot is written on every interval, or only when a threshold is exceeded
+    pub mode: ExportMode,
+
+    /// The interval in minutes between each snapshot
+    ///
+    /// This is only used in the continuous mode.
+    pub interval_minutes: u64,
+
+    /// The directory the snapshots are written to
+    ///
+    /// If this is not set, the `exports` directory in the application's data directory is used.
+    pub directory: Option<std::path::PathBuf>,
+}
+
+impl Default for ScheduledExport {
+    /// Create the export settings with the default values.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: ExportMode::Continuous,
+            interval_minutes: 5,
+            directory: None,
+        }
+    }
+}
+
+/// When the system info is written to disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportMode {
+    /// A full snapshot is written on every interval
+    Continuous,
+
+    /// A row is appended to the threshold event log whenever a metric exceeds its threshold
+    ///
+    /// This keeps the exports small, while still recording every incident.
+    EventsOnly,
+}
+
+/// A metric read from an external command, for values the application does not collect itself
+///
+/// # Example
+///
+/// ```toml
+/// [[custom_metrics]]
+/// name = "GPU temperature"
+/// command = "nvidia-smi"
+/// args = ["--query-gpu=temperature.gpu", "--format=csv,noheader"]
+/// unit = "°C"
+/// max = 100.0
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomMetric {
+    /// The name shown above the value
+    pub name: String,
+
+    /// The program to run
+    ///
+    /// The program is run directly, not through a shell.
+    pub command: String,
+
+    /// The arguments passed to the program
+    pub args: Vec<String>,
+
+    /// The unit shown after the value
+    pub unit: String,
+
+    /// The value at which the bar below the value is full
+    ///
+    /// No bar is shown if this is not set.
+    pub max: Option<f32>,
+}
+
+impl Default for CustomMetric {
+    /// Create a custom metric with no command.
+    fn default() -> Self {
+        Self {
+            name: "Custom metric".to_string(),
+            command: String::new(),
+            args: Vec::new(),
+            unit: String::new(),
+            max: None,
+        }
+    }
+}
+
+/// Which categories of system info are collected and shown
+///
+/// Only the enabled categories are refreshed on each update, and the panels of the
+/// disabled categories are hidden. Disabling the categories the user does not need
+/// reduces the overhead of the application itself.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnabledMetrics {
+    /// The cpu usage and frequency
+    pub cpu: bool,
+
+    /// The memory and swap usage
+    pub memory: bool,
+
+    /// The disk usage
+    pub disks: bool,
+
+    /// The network usage
+    pub networks: bool,
+
+    /// The list of processes
+    pub processes: bool,
+
+    /// The temperature sensors
+    pub components: bool,
+}
+
+impl EnabledMetrics {
+    /// Returns whether the data needed for a metric is collected
+    pub fn includes(&self, metric: Metric) -> bool {
+        match metric {
+            Metric::Cpu => self.cpu,
+            Metric::Memory | Metric::Swap => self.memory,
+            Metric::Temperature => self.components,
+        }
+    }
+}
+
+impl Default for EnabledMetrics {
+    /// Enable every category, the same as refreshing everything.
+    fn default() -> Self {
+        Self {
+            cpu: true,
+            memory: true,
+            disks: true,
+            networks: true,
+            processes: true,
+            components: true,
+        }
+    }
+}
+
+impl Default for Thresholds {
+    /// Create the thresholds with the default values.
+    fn default() -> Self {
+        Self {
+            cpu: 90.0,
+            memory: 90.0,
+            swap: 50.0,
+            temperature: 85.0,
+        }
+    }
 }
 
 impl Config {
+    /// Use the given path for the config file instead of the default one
+    ///
+    /// This should be called at startup, before the config is loaded.
+    /// Only the first call has an effect.
+    pub fn set_config_path_override(path: PathBuf) {
+        if CONFIG_PATH_OVERRIDE.set(path).is_err() {
+            eprintln!("The config path was already set, ignoring the new path");
+        }
+    }
+
     /// Get the path to the config file
     ///
     /// This function will return the path to the config file.
-    fn get_config_path() -> std::path::PathBuf {
+    /// The path is resolved in this order:
+    ///  1. The path given with the `--config` command line argument
+    ///  2. The path in the `ICY_SYSMONITOR_CONFIG` environment variable
+    ///  3. `config.toml` in the platform's config directory
+    pub fn get_config_path() -> PathBuf {
+        if let Some(path) = CONFIG_PATH_OVERRIDE.get() {
+            return path.clone();
+        }
+
+        if let Some(path) = std::env::var_os(CONFIG_PATH_ENV_VAR) {
+            if !path.is_empty() {
+                return PathBuf::from(path);
+            }
+        }
+
         let project_dirs = ProjectDirs::from("io.github", "DitherWither", "icy-sysmonitor")
             .expect("Could not get project directories"); // TODO: Remove this expect
 
@@ -25,6 +692,211 @@ impl Config {
         config_dir.join("config.toml")
     }
 
+    /// Get the directory that the saved profiles are stored in
+    ///
+    /// Each profile is a complete config file named after the profile,
+    /// in a `profiles` directory next to the config file.
+    pub fn get_profiles_dir() -> PathBuf {
+        let config_path = Self::get_config_path();
+
+        config_path
+            .parent()
+            .expect("The config file has no parent directory. This should never happen.")
+            .join("profiles")
+    }
+
+    /// Returns the names of the saved profiles, sorted alphabetically
+    ///
+    /// An empty list is returned if the profiles directory does not exist yet.
+    pub fn list_profiles() -> Vec<String> {
+        let entries = match std::fs::read_dir(Self::get_profiles_dir()) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut profiles: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .map_or(false, |extension| extension == "toml")
+            })
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+
+        profiles.sort();
+        profiles
+    }
+
+    /// Returns whether a profile name can be used as a file name
+    ///
+    /// Names that would point outside of the profiles directory are not allowed.
+    /// Surrounding spaces are not part of the name, so they are left out of the check.
+    pub fn is_valid_profile_name(name: &str) -> bool {
+        let name = name.trim();
+
+        !name.is_empty()
+            && !name.starts_with('.')
+            && !name.contains(|c: char| c == '/' || c == '\\')
+    }
+
+    /// Load a saved profile
+    ///
+    /// The loaded config remembers the name of the profile in `current_profile`.
+    /// Unlike `load_async`, a profile that cannot be parsed is reported instead of falling back
+    /// to the defaults, as the profile replaces the config that is in use.
+    pub fn load_profile(name: &str) -> Result<Self, String> {
+        if !Self::is_valid_profile_name(name) {
+            return Err(format!("{name} is not a valid profile name"));
+        }
+
+        let name = name.trim();
+        let path = Self::get_profiles_dir().join(format!("{name}.toml"));
+
+        let config = std::fs::read_to_string(&path)
+            .map_err(|error| format!("Could not read the profile {name}: {error}"))?;
+
+        let mut config = Self::parse_strict(&config, &path)?;
+        config.current_profile = Some(name.to_string());
+
+        Ok(config)
+    }
+
+    /// Save the config as a profile with the given name
+    ///
+    /// An existing profile with the same name is overwritten.
+    /// Surrounding spaces are left out of the file name.
+    pub fn save_profile(&self, name: &str) -> io::Result<()> {
+        if !Self::is_valid_profile_name(name) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The profile name is not a valid file name",
+            ));
+        }
+
+        let name = name.trim();
+
+        let profiles_dir = Self::get_profiles_dir();
+        std::fs::create_dir_all(&profiles_dir)?;
+
+        let config_str = toml::to_string(&self)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        std::fs::write(profiles_dir.join(format!("{name}.toml")), config_str)
+    }
+
+    /// Read a config file, reporting an invalid file instead of falling back to the defaults
+    ///
+    /// This is used where the config is replacing one that is already in use, so that
+    /// a broken file does not replace it by accident, and is not overwritten on the next save.
+    /// Fields that are missing from the file are filled in with their default values.
+    async fn read_file(path: &Path) -> Result<Self, String> {
+        let config = tokio::fs::read_to_string(path)
+            .await
+            .map_err(|error| format!("Could not read {}: {error}", path.display()))?;
+
+        Self::parse_strict(&config, path)
+    }
+
+    /// Parse the contents of a config file, returning an error instead of the defaults
+    ///
+    /// The values are checked as well, as the file may not have been written by the settings page.
+    /// The path is only used in the error message.
+    fn parse_strict(config: &str, path: &Path) -> Result<Self, String> {
+        let config: Self = toml::from_str(config)
+            .map_err(|error| format!("{} is not a valid config file: {error}", path.display()))?;
+
+        config
+            .validate()
+            .map_err(|error| format!("{} has an invalid setting: {error}", path.display()))?;
+
+        Ok(config)
+    }
+
+    /// Read only the font from the config file
+    ///
+    /// The font has to be known before the window is created, while the rest of the config
+    /// is loaded in the background. Nothing is printed when the file cannot be read or parsed,
+    /// as the full load reports that.
+    pub fn read_font() -> Option<PathBuf> {
+        /// The part of the config file that is needed before the window is created
+        #[derive(Deserialize)]
+        struct FontOnly {
+            font: Option<PathBuf>,
+        }
+
+        let config = std::fs::read_to_string(Self::get_config_path()).ok()?;
+
+        toml::from_str::<FontOnly>(&config).ok()?.font
+    }
+
+    /// Read the config file again after it was changed outside of the application
+    ///
+    /// Unlike `load_async`, an invalid file is reported instead of falling back to the defaults,
+    /// as it may just be a hand edit that is not finished yet.
+    pub async fn reload_async() -> Result<Self, String> {
+        Self::read_file(&Self::get_config_path()).await
+    }
+
+    /// Read a config file that was exported or shared by another user
+    ///
+    /// Like `reload_async`, an invalid file is reported instead of falling back to the defaults.
+    pub async fn import_file(path: PathBuf) -> Result<Self, String> {
+        let mut config = Self::read_file(&path).await?;
+
+        // The imported file is not one of the saved profiles
+        config.current_profile = None;
+
+        Ok(config)
+    }
+
+    /// Checks that the values are in the ranges the settings page allows
+    ///
+    /// Returns a description of the first invalid value.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(100..=10_000).contains(&self.update_interval) {
+            return Err(format!(
+                "update_interval must be between 100 and 10000 milliseconds, not {}",
+                self.update_interval
+            ));
+        }
+
+        if self.temperature_interval == 0 {
+            return Err("temperature_interval must be more than 0 milliseconds".to_string());
+        }
+
+        if self.unfocused_interval_factor == 0 {
+            return Err("unfocused_interval_factor must be at least 1".to_string());
+        }
+
+        if self.bar_colors.yellow >= self.bar_colors.red {
+            return Err(format!(
+                "bar_colors.yellow ({}) must be below bar_colors.red ({})",
+                self.bar_colors.yellow, self.bar_colors.red
+            ));
+        }
+
+        if let Some(ratio) = self.home_split_ratio {
+            if !(0.0..=1.0).contains(&ratio) {
+                return Err(format!(
+                    "home_split_ratio must be between 0 and 1, not {ratio}"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the config to a file chosen by the user, to back it up or share it
+    pub async fn export_file(&self, path: PathBuf) -> Result<(), String> {
+        let config_str = toml::to_string(&self)
+            .map_err(|error| format!("Could not serialize the config: {error}"))?;
+
+        tokio::fs::write(&path, config_str)
+            .await
+            .map_err(|error| format!("Could not write {}: {error}", path.display()))
+    }
+
     /// Ensures that the config file's parent directory exists
     ///
     /// This function will ensure that the config file's parent directory exists.
@@ -45,7 +917,7 @@ impl Config {
     /// This function will panic if the config file has no parent directory.
     ///
     /// This should never happen as the config file is always in a directory.
-    fn ensure_config_dir_exists() -> io::Result<()> {
+    pub fn ensure_config_dir_exists() -> io::Result<()> {
         let config_path = Self::get_config_path();
 
         // The directory that the config file is in
@@ -72,74 +944,118 @@ impl Config {
         }
     }
 
-    /// Load the config from disk
+    /// Load the config from disk without blocking the calling thread
     ///
     /// This function will load the config from disk and return it.
     /// If the config file does not exist, it will create a new one
     /// with the default values.
-    pub fn load() -> Self {
+    /// The file is read and written with tokio, so that this can be run as an iced `Command`
+    /// without stalling the user interface.
+    pub async fn load_async() -> Self {
         let config_path = Self::get_config_path();
 
-        // Load the config from disk if it exists
-        // TODO: Make this display a dialog instead of printing to stderr
-        if config_path.exists() {
-            let config = match std::fs::read_to_string(config_path) {
-                Ok(config) => config,
-                Err(_) => {
-                    eprintln!("Could not read config file, defaulting to default values");
-                    eprintln!("Please check the permissions of the config file");
+        match tokio::fs::read_to_string(&config_path).await {
+            Ok(config) => Self::parse(&config),
 
-                    return Self::default();
-                }
-            };
+            // The config file is only missing on the first launch
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Self::create_default_async().await
+            }
 
-            match toml::from_str(&config) {
-                Ok(config) => config,
-                Err(_) => {
-                    eprintln!("Could not parse config file, defaulting to default values");
-                    eprintln!("Please check the config file for errors");
+            // TODO: Make this display a dialog instead of printing to stderr
+            Err(_) => {
+                eprintln!("Could not read config file, defaulting to default values");
+                eprintln!("Please check the permissions of the config file");
 
-                    Self::default()
-                }
+                Self::default()
             }
-        } else {
-            // Create the config directory if it does not exist
-            match Self::ensure_config_dir_exists() {
-                Ok(_) => {}
-                Err(_) => {
-                    // The error is already printed in the function
-                    return Self::default();
-                }
+        }
+    }
+
+    /// Write a config file with the default values without blocking the calling thread
+    ///
+    /// The default config is returned, even if it could not be written.
+    async fn create_default_async() -> Self {
+        let config_path = Self::get_config_path();
+        let config = Self::default();
+
+        // Create the config directory if it does not exist
+        if let Some(config_dir) = config_path.parent() {
+            if tokio::fs::create_dir_all(config_dir).await.is_err() {
+                eprintln!("Could not create config directory");
+                eprintln!("Please check the permissions of the config directory");
+
+                return config;
             }
+        }
 
-            // Create a new config with the default values
-            let config = Self::default();
+        // Write the config to disk
+        let config_str = match toml::to_string(&config) {
+            Ok(config) => config,
+            Err(_) => {
+                eprintln!("Could not serialize the default config");
+                eprintln!("How did this even happen?");
 
-            // Write the config to disk
-            let config_str = match toml::to_string(&config) {
-                Ok(config) => config,
-                Err(_) => {
-                    eprintln!("Could not serialize the default config");
-                    eprintln!("How did this even happen?");
+                return config;
+            }
+        };
 
-                    return config;
-                }
-            };
+        if write_atomically_async(&config_path, &config_str)
+            .await
+            .is_err()
+        {
+            eprintln!("Could not write config file, defaulting to default values");
+            eprintln!("Please check the permissions of the config directory");
+        }
 
-            match std::fs::write(config_path, config_str) {
-                Ok(_) => {}
-                Err(_) => {
-                    eprintln!("Could not write config file, defaulting to default values");
-                    eprintln!("Please check the permissions of the config directory");
+        config
+    }
 
-                    return config;
+    /// Parse the contents of a config file
+    ///
+    /// The default values are returned if the config could not be parsed,
+    /// or has a value outside of the ranges the settings page allows.
+    fn parse(config: &str) -> Self {
+        // TODO: Make this display a dialog instead of printing to stderr
+        match toml::from_str::<Self>(config) {
+            Ok(config) => match config.validate() {
+                Ok(()) => config,
+                Err(error) => {
+                    eprintln!("{error}");
+                    eprintln!("Could not load config file, defaulting to default values");
+                    eprintln!("Please check the config file for errors");
+
+                    Self::default()
                 }
-            }
+            },
+            Err(_) => {
+                eprintln!("Could not parse config file, defaulting to default values");
+                eprintln!("Please check the config file for errors");
 
-            config
+                Self::default()
+            }
         }
     }
 
+    /// Returns the time the config file was last modified
+    ///
+    /// Returns `None` if the config file does not exist or its time could not be read.
+    pub fn get_modified_time() -> Option<std::time::SystemTime> {
+        std::fs::metadata(Self::get_config_path())
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// Returns whether the other config has the same values as this one, apart from the update interval
+    ///
+    /// The configs are compared in their serialized form, so that every field is included.
+    pub fn matches_apart_from_update_interval(&self, other: &Config) -> bool {
+        let mut other = other.clone();
+        other.update_interval = self.update_interval;
+
+        toml::to_string(self).ok() == toml::to_string(&other).ok()
+    }
+
     /// Save the config to disk
     ///
     /// This function will save the config to disk.
@@ -160,7 +1076,7 @@ impl Config {
         // Write the config to disk
         let config_str = toml::to_string(&self).expect("Could not serialize config");
 
-        std::fs::write(config_path, config_str).expect("Could not write config file");
+        write_atomically(&config_path, &config_str).expect("Could not write config file");
     }
 }
 
@@ -169,6 +1085,88 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             update_interval: 1000,
+            navigation: NavigationLayout::TabBar,
+            show_title: true,
+            live_title: false,
+            show_cpu_brand: true,
+            highlight_changes: true,
+            highlight_delta: 30.0,
+            temperature_interval: 2000,
+            bar_palette: BarPalette::TrafficLight,
+            throttle_when_unfocused: false,
+            unfocused_interval_factor: 5,
+            compact_mode: false,
+            navigation_icons: true,
+            percent_rounding: PercentRounding::Round,
+            show_core_temperatures: false,
+            size_unit: SizeUnit::Auto,
+            collapse_idle_cores: false,
+            idle_core_threshold: 1.0,
+            watched_paths: Vec::new(),
+            network_bits: false,
+            usage_display: UsageDisplay::Bar,
+            font: None,
+            alert_sound: false,
+            stacked_cpu_bars: false,
+            header_summary: false,
+            cpu_layout: CpuLayout::Rows,
+            bar_width: None,
+            current_profile: None,
+            show_load_average: false,
+            show_available_memory: false,
+            tab_bar_position: TabBarPosition::Top,
+            confirm_on_quit: false,
+            memory_bar: MemoryBarMode::Memory,
+            show_core_chart: false,
+            reduce_work_when_busy: false,
+            animate_bars: false,
+            cpu_peak_hold: false,
+            home_split_ratio: None,
+            thresholds: Thresholds::default(),
+            bar_colors: BarColorThresholds::default(),
+            scheduled_export: ScheduledExport::default(),
+            metrics: EnabledMetrics::default(),
+            custom_metrics: Vec::new(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_names_are_checked_without_surrounding_spaces() {
+        assert!(Config::is_valid_profile_name("  gaming  "));
+        assert!(!Config::is_valid_profile_name(" .hidden"));
+        assert!(!Config::is_valid_profile_name("   "));
+    }
+
+    #[test]
+    fn profile_names_cannot_leave_the_profiles_directory() {
+        assert!(!Config::is_valid_profile_name("../config"));
+        assert!(!Config::is_valid_profile_name("profiles\\config"));
+        assert!(!Config::is_valid_profile_name(".."));
+    }
+
+    #[test]
+    fn reloaded_config_with_an_invalid_value_is_reported() {
+        let path = Path::new("config.toml");
+
+        assert!(Config::parse_strict("update_interval = 2500", path).is_ok());
+
+        let error = Config::parse_strict("update_interval = 50", path).unwrap_err();
+        assert!(error.contains("update_interval"));
+    }
+
+    #[test]
+    fn loaded_config_with_an_invalid_value_falls_back_to_the_defaults() {
+        let config = Config::parse("update_interval = 2500\ntemperature_interval = 0");
+
+        assert_eq!(config.update_interval, Config::default().update_interval);
+        assert_eq!(
+            config.temperature_interval,
+            Config::default().temperature_interval
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! A stacked area chart of the usage of each cpu over time
+//!
+//! Each cpu is a band, stacked on top of the bands of the cpus before it, so the top of
+//! the chart is the total usage and the bands show how it is spread over the cpus.
+
+use iced::{
+    widget::canvas::{self, Canvas, Frame, Geometry, Path},
+    Point, Rectangle, Theme,
+};
+
+use crate::thresholds::bar_percentage;
+
+/// The height of the chart, in pixels
+const CHART_HEIGHT: u16 = 120;
+
+/// A stacked area chart of the usage of each cpu, drawn on a canvas
+struct CoreChart {
+    /// The usage of each cpu in percent at each update, from the oldest to the newest
+    ///
+    /// Every sample has the same number of cpus.
+    samples: Vec<Vec<f32>>,
+}
+
+impl<Message> canvas::Program<Message> for CoreChart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: canvas::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+
+        let cpu_count = self.samples.first().map_or(0, |sample| sample.len());
+
+        if self.samples.len() < 2 || cpu_count == 0 {
+            return vec![frame.into_geometry()];
+        }
+
+        let step = bounds.width / (self.samples.len() - 1) as f32;
+
+        // The height of the top of the band of `cpu` at each sample
+        // Each cpu adds its share of the total usage, so the full stack is 100% at most
+        let band_top = |cpu: usize| -> Vec<f32> {
+            self.samples
+                .iter()
+                .map(|sample| {
+                    let stacked: f32 = sample
+                        .iter()
+                        .take(cpu + 1)
+                        .map(|usage| bar_percentage(*usage))
+                        .sum();
+                    bounds.height * (1.0 - stacked / (100.0 * cpu_count as f32))
+                })
+                .collect()
+        };
+
+        let mut bottom = vec![bounds.height; self.samples.len()];
+        let base_color = theme.extended_palette().primary.base.color;
+
+        for cpu in 0..cpu_count {
+            let top = band_top(cpu);
+
+            // The outline of the band goes right along the top, and back left along the bottom
+            let band = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, top[0]));
+
+                for (i, y) in top.iter().enumerate().skip(1) {
+                    builder.line_to(Point::new(i as f32 * step, *y));
+                }
+
+                for (i, y) in bottom.iter().enumerate().rev() {
+                    builder.line_to(Point::new(i as f32 * step, *y));
+                }
+
+                builder.close();
+            });
+
+            // Alternate the shade of the bands, so that neighboring cpus can be told apart
+            let mut color = base_color;
+            color.a = if cpu % 2 == 0 { 0.9 } else { 0.5 };
+
+            frame.fill(&band, color);
+
+            bottom = top;
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Returns a stacked area chart of the usage of each cpu over time
+///
+/// Every sample should have the usage of every cpu, in the same order.
+/// Usages that are not a number or outside of 0 to 100 are drawn like a progress bar would.
+pub fn core_chart<'a, Message: 'a>(samples: Vec<Vec<f32>>) -> iced::Element<'a, Message> {
+    Canvas::new(CoreChart { samples })
+        .width(iced::Length::Fill)
+        .height(iced::Length::Units(CHART_HEIGHT))
+        .into()
+}
@@ -0,0 +1,155 @@
+//! Breakdown of the time spent by each cpu in user, system and idle mode
+//!
+//! sysinfo only reports the total usage of each cpu, so the raw cpu times are read
+//! from the platform directly. This is currently only supported on Linux, where the
+//! times are exposed in `/proc/stat`.
+//! On other platforms no times are reported, and only the total usage is shown.
+
+/// The cumulative time spent by a single cpu in each mode since boot, in clock ticks
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub struct CpuTimes {
+    /// Time spent running user space code, including niced processes
+    pub user: u64,
+
+    /// Time spent running kernel code, including servicing interrupts
+    pub system: u64,
+
+    /// Time spent idle, including waiting for I/O
+    pub idle: u64,
+
+    /// Time stolen by the hypervisor on virtual machines
+    pub steal: u64,
+}
+
+impl CpuTimes {
+    /// The total time of the cpu in all modes
+    fn total(&self) -> u64 {
+        self.user + self.system + self.idle + self.steal
+    }
+}
+
+/// The share of time a single cpu spent in each mode between two readings, in percent
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuTimesBreakdown {
+    /// Percentage of time spent running user space code
+    pub user: f32,
+
+    /// Percentage of time spent running kernel code
+    pub system: f32,
+
+    /// Percentage of time spent idle
+    pub idle: f32,
+}
+
+impl CpuTimesBreakdown {
+    /// Computes the breakdown of the time elapsed between two readings of the same cpu
+    ///
+    /// Returns `None` if no time has elapsed between the readings.
+    pub fn between(previous: &CpuTimes, current: &CpuTimes) -> Option<Self> {
+        let elapsed = current.total().checked_sub(previous.total())?;
+
+        if elapsed == 0 {
+            return None;
+        }
+
+        let percentage = |previous: u64, current: u64| {
+            current.saturating_sub(previous) as f32 / elapsed as f32 * 100.0
+        };
+
+        Some(Self {
+            user: percentage(previous.user, current.user),
+            system: percentage(previous.system, current.system),
+            idle: percentage(previous.idle, current.idle),
+        })
+    }
+}
+
+/// Reads the cumulative times of every cpu, in the order the platform lists them
+///
+/// This function reads the per-cpu lines of `/proc/stat`.
+/// Returns `None` if the times could not be read.
+#[cfg(target_os = "linux")]
+pub fn read_cpu_times() -> Option<Vec<CpuTimes>> {
+    let stat = std::fs::read_to_string("/proc/stat").ok()?;
+
+    let times = stat
+        .lines()
+        // The per-cpu lines start with cpu0, cpu1, etc.
+        // The line that starts with just "cpu" is the total of all cpus
+        .filter(|line| {
+            line.starts_with("cpu") && line[3..].starts_with(|c: char| c.is_ascii_digit())
+        })
+        .filter_map(parse_cpu_line)
+        .collect();
+
+    Some(times)
+}
+
+/// Reads the cumulative times of every cpu
+///
+/// Cpu times are not supported on this platform, so `None` is returned.
+#[cfg(not(target_os = "linux"))]
+pub fn read_cpu_times() -> Option<Vec<CpuTimes>> {
+    None
+}
+
+/// Parses a single cpu line of `/proc/stat`
+///
+/// The line looks like this, with the fields being
+/// user, nice, system, idle, iowait, irq, softirq and steal:
+///
+/// ```text
+/// cpu0 4705 356 584 3699176 23060 0 277 0 0 0
+/// ```
+#[cfg(target_os = "linux")]
+fn parse_cpu_line(line: &str) -> Option<CpuTimes> {
+    let fields: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .map(|field| field.parse().ok())
+        .collect::<Option<_>>()?;
+
+    // Older kernels do not report the steal time
+    let field = |index: usize| fields.get(index).copied().unwrap_or(0);
+
+    Some(CpuTimes {
+        user: field(0) + field(1),
+        system: field(2) + field(5) + field(6),
+        idle: field(3) + field(4),
+        steal: field(7),
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cpu_line_groups_the_modes() {
+        let times = parse_cpu_line("cpu0 4705 356 584 3699176 23060 0 277 12 0 0").unwrap();
+
+        // user + nice
+        assert_eq!(times.user, 5061);
+        // system + irq + softirq
+        assert_eq!(times.system, 861);
+        // idle + iowait
+        assert_eq!(times.idle, 3722236);
+        assert_eq!(times.steal, 12);
+    }
+
+    #[test]
+    fn parse_cpu_line_accepts_lines_without_steal_time() {
+        let times = parse_cpu_line("cpu1 100 0 50 1000 10 0 5").unwrap();
+
+        assert_eq!(times.user, 100);
+        assert_eq!(times.system, 55);
+        assert_eq!(times.idle, 1010);
+        assert_eq!(times.steal, 0);
+    }
+
+    #[test]
+    fn parse_cpu_line_rejects_fields_that_are_not_numbers() {
+        assert!(parse_cpu_line("cpu0 4705 abc 584 3699176").is_none());
+    }
+}
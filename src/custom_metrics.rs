@@ -0,0 +1,63 @@
+//! Metrics read from external commands
+//!
+//! Custom metrics let users show values that the application does not collect itself,
+//! such as the temperature of a GPU, by running a command that prints a number.
+//! The commands are run with tokio, so a slow command never stalls the user interface.
+
+use std::time::Duration;
+
+use tokio::process::Command;
+
+use crate::config::CustomMetric;
+
+/// How long a command may run before its value is given up on
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Runs the command of every custom metric, and returns their values in the same order
+///
+/// The commands are run at the same time, so a slow command does not delay the others.
+/// A metric whose command fails, or does not print a number, gets an error message instead.
+pub async fn read_custom_metrics(metrics: Vec<CustomMetric>) -> Vec<Result<f32, String>> {
+    let mut handles = Vec::new();
+
+    for metric in metrics {
+        handles.push(tokio::spawn(read_custom_metric(metric)));
+    }
+
+    let mut values = Vec::new();
+
+    for handle in handles {
+        values.push(
+            handle
+                .await
+                .unwrap_or_else(|_| Err("The command could not be run".to_string())),
+        );
+    }
+
+    values
+}
+
+/// Runs the command of a custom metric, and parses its output as a number
+async fn read_custom_metric(metric: CustomMetric) -> Result<f32, String> {
+    let output = Command::new(&metric.command)
+        .args(&metric.args)
+        .kill_on_drop(true)
+        .output();
+
+    let output = match tokio::time::timeout(COMMAND_TIMEOUT, output).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(error)) => return Err(format!("Could not run {}: {error}", metric.command)),
+        Err(_) => return Err(format!("{} took too long", metric.command)),
+    };
+
+    if !output.status.success() {
+        return Err(format!("{} failed ({})", metric.command, output.status));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    stdout
+        .trim()
+        .parse()
+        .map_err(|_| format!("Not a number: {}", stdout.trim()))
+}
@@ -0,0 +1,190 @@
+//! Writing snapshots of the system info to disk
+//!
+//! The snapshots are written as CSV files with a timestamped name, so that they
+//! can be archived and compared over long periods of time.
+//! In the events-only mode, a single row is appended to a log of threshold events instead.
+//! The current metrics can also be formatted as Markdown tables, to paste into bug reports.
+
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use directories_next::ProjectDirs;
+use sysinfo::{CpuExt, ProcessExt, System, SystemExt};
+
+use crate::{config::SizeUnit, thresholds::ThresholdCrossing};
+
+/// The name of the file that threshold events are appended to
+const THRESHOLD_EVENTS_FILE: &str = "threshold-events.csv";
+
+/// The number of processes listed in the Markdown summary, starting with the busiest
+const MARKDOWN_PROCESS_COUNT: usize = 10;
+
+/// Returns the directory that snapshots are written to when none is set in the config
+///
+/// This is the `exports` directory inside the platform's data directory for the application.
+pub fn default_export_dir() -> Option<PathBuf> {
+    let project_dirs = ProjectDirs::from("io.github", "DitherWither", "icy-sysmonitor")?;
+
+    Some(project_dirs.data_dir().join("exports"))
+}
+
+/// Writes a snapshot of the current system info to a new file in `directory`
+///
+/// The directory is created if it does not exist.
+/// The file is called `snapshot-<unix timestamp>.csv`, and its path is returned.
+pub fn write_snapshot(sys: &System, directory: &Path) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(directory)?;
+
+    let timestamp = unix_timestamp();
+
+    let path = directory.join(format!("snapshot-{timestamp}.csv"));
+    std::fs::write(&path, snapshot_csv(sys, timestamp))?;
+
+    Ok(path)
+}
+
+/// Appends a row for each threshold crossing to the threshold event log in `directory`
+///
+/// The directory and the log are created if they do not exist, and the header row
+/// is written when the log is created.
+///
+/// # Example
+///
+/// ```text
+/// timestamp,metric,value,threshold
+/// 1676000000,CPU,93.20,90.00
+/// ```
+pub fn append_threshold_events(
+    crossings: &[ThresholdCrossing],
+    directory: &Path,
+) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(directory)?;
+
+    let path = directory.join(THRESHOLD_EVENTS_FILE);
+    let is_new = !path.exists();
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+
+    if is_new {
+        writeln!(file, "timestamp,metric,value,threshold")?;
+    }
+
+    let timestamp = unix_timestamp();
+
+    for crossing in crossings {
+        writeln!(
+            file,
+            "{timestamp},{},{:.2},{:.2}",
+            crossing.metric.name(),
+            crossing.value,
+            crossing.threshold
+        )?;
+    }
+
+    Ok(path)
+}
+
+/// Returns the current time as seconds since the unix epoch
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Formats the current system info as a CSV table with a header row and a single data row
+///
+/// # Example
+///
+/// ```text
+/// timestamp,cpu_total,cpu0,cpu1,memory_used,memory_total,swap_used,swap_total
+/// 1676000000,12.50,10.00,15.00,1073741824,8364687360,0,2147483648
+/// ```
+fn snapshot_csv(sys: &System, timestamp: u64) -> String {
+    let mut header = vec!["timestamp".to_string(), "cpu_total".to_string()];
+    let mut values = vec![
+        timestamp.to_string(),
+        format!("{:.2}", sys.global_cpu_info().cpu_usage()),
+    ];
+
+    for (i, cpu) in sys.cpus().iter().enumerate() {
+        header.push(format!("cpu{i}"));
+        values.push(format!("{:.2}", cpu.cpu_usage()));
+    }
+
+    header.extend(["memory_used", "memory_total", "swap_used", "swap_total"].map(str::to_string));
+    values.extend(
+        [
+            sys.used_memory(),
+            sys.total_memory(),
+            sys.used_swap(),
+            sys.total_swap(),
+        ]
+        .map(|value| value.to_string()),
+    );
+
+    format!("{}\n{}\n", header.join(","), values.join(","))
+}
+
+/// Formats the current system info as Markdown tables, for pasting into issues and docs
+///
+/// The processes table is only included with `include_processes`, as the process list
+/// is not refreshed when the processes are disabled in the config.
+///
+/// # Example
+///
+/// ```text
+/// | Metric | Value |
+/// | --- | --- |
+/// | CPU | 12.5% |
+/// | CPU 0 | 10.0% |
+/// | Memory | 1.0 GB / 8.4 GB |
+/// | Swap | 0 B / 2.1 GB |
+///
+/// | Process | PID | CPU | Memory |
+/// | --- | --- | --- | --- |
+/// | firefox | 4242 | 8.3% | 512.0 MB |
+/// ```
+pub fn snapshot_markdown(sys: &System, size_unit: SizeUnit, include_processes: bool) -> String {
+    let mut markdown = String::from("| Metric | Value |\n| --- | --- |\n");
+
+    markdown += &format!("| CPU | {:.1}% |\n", sys.global_cpu_info().cpu_usage());
+
+    for (i, cpu) in sys.cpus().iter().enumerate() {
+        markdown += &format!("| CPU {i} | {:.1}% |\n", cpu.cpu_usage());
+    }
+
+    markdown += &format!(
+        "| Memory | {} / {} |\n| Swap | {} / {} |\n",
+        size_unit.format(sys.used_memory()),
+        size_unit.format(sys.total_memory()),
+        size_unit.format(sys.used_swap()),
+        size_unit.format(sys.total_swap())
+    );
+
+    if include_processes {
+        let mut processes: Vec<_> = sys.processes().values().collect();
+        processes.sort_by(|a, b| b.cpu_usage().total_cmp(&a.cpu_usage()));
+
+        markdown += "\n| Process | PID | CPU | Memory |\n| --- | --- | --- | --- |\n";
+
+        for process in processes.into_iter().take(MARKDOWN_PROCESS_COUNT) {
+            markdown += &format!(
+                "| {} | {} | {:.1}% | {} |\n",
+                // A pipe in the name would end the cell early
+                process.name().replace('|', "\\|"),
+                process.pid(),
+                process.cpu_usage(),
+                size_unit.format(process.memory())
+            );
+        }
+    }
+
+    markdown
+}
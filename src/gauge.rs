@@ -0,0 +1,116 @@
+//! A circular gauge showing a usage percentage
+//!
+//! The gauge is an alternative to the progress bars, drawn on a canvas as a half circle
+//! with a needle pointing at the current value. It uses the same colors as the bars.
+
+use std::f32::consts::PI;
+
+use iced::{
+    widget::canvas::{self, path::Arc, Canvas, Frame, Geometry, Path, Stroke},
+    Point, Rectangle, Theme,
+};
+
+use crate::{
+    config::{BarPalette, Config},
+    style::usage_color,
+    thresholds::{bar_severity, Severity},
+};
+
+/// The height of a gauge, in pixels
+const GAUGE_HEIGHT: u16 = 100;
+
+/// The width of the arc of a gauge, in pixels
+const ARC_WIDTH: f32 = 10.0;
+
+/// A gauge showing a usage percentage, drawn on a canvas
+struct Gauge {
+    /// The color scheme of the gauge
+    palette: BarPalette,
+
+    /// The usage shown by the gauge, between 0 and 100
+    percentage: f32,
+
+    /// The severity of the usage, used by the traffic-light palette
+    severity: Severity,
+}
+
+impl<Message> canvas::Program<Message> for Gauge {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: canvas::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+
+        // The gauge is the top half of a circle, standing on the bottom of the bounds
+        let radius = (bounds.width / 2.0).min(bounds.height) - ARC_WIDTH;
+        let center = Point::new(bounds.width / 2.0, bounds.height - ARC_WIDTH / 2.0);
+
+        if radius <= 0.0 {
+            return vec![frame.into_geometry()];
+        }
+
+        // The angles grow clockwise, so the arc goes from the left through the top to the right
+        let value_angle = PI + PI * (self.percentage / 100.0).clamp(0.0, 1.0);
+
+        let arc = |end_angle: f32| {
+            Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius,
+                    start_angle: PI,
+                    end_angle,
+                })
+            })
+        };
+
+        let palette = theme.extended_palette();
+        let value_color = usage_color(self.palette, self.percentage, self.severity, theme);
+
+        // The track of the gauge, and the part of it that is filled
+        frame.stroke(
+            &arc(2.0 * PI),
+            Stroke::default()
+                .with_width(ARC_WIDTH)
+                .with_color(palette.background.strong.color),
+        );
+        frame.stroke(
+            &arc(value_angle),
+            Stroke::default()
+                .with_width(ARC_WIDTH)
+                .with_color(value_color),
+        );
+
+        // The needle points at the current value
+        let needle_end = Point::new(
+            center.x + radius * value_angle.cos(),
+            center.y + radius * value_angle.sin(),
+        );
+        frame.stroke(
+            &Path::line(center, needle_end),
+            Stroke::default()
+                .with_width(3.0)
+                .with_color(palette.background.base.text),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Returns a gauge showing a usage percentage
+///
+/// The gauge is colored according to the bar palette in the config, like the progress bars.
+pub fn gauge<'a, Message: 'a>(percentage: f32, config: &Config) -> iced::Element<'a, Message> {
+    Canvas::new(Gauge {
+        palette: config.bar_palette,
+        percentage,
+        severity: bar_severity(percentage, config),
+    })
+    .width(iced::Length::Fill)
+    .height(iced::Length::Units(GAUGE_HEIGHT))
+    .into()
+}
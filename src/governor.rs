@@ -0,0 +1,34 @@
+//! The frequency scaling governor of the cpus
+//!
+//! The governor decides how the cpu frequencies follow the load, which explains
+//! why the frequencies and usage behave as they do, for example a `powersave` governor
+//! keeping the frequencies low. This is currently only supported on Linux, where the
+//! governor is exposed in sysfs.
+//! On other platforms no governor is reported.
+
+/// Reads the frequency scaling governor of the first cpu, for example "powersave"
+///
+/// The governor is almost always the same for every cpu, so only the first one is read.
+/// Returns `None` if the governor could not be read, for example on virtual machines
+/// without frequency scaling.
+#[cfg(target_os = "linux")]
+pub fn read_scaling_governor() -> Option<String> {
+    let governor =
+        std::fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor").ok()?;
+
+    let governor = governor.trim();
+
+    if governor.is_empty() {
+        None
+    } else {
+        Some(governor.to_string())
+    }
+}
+
+/// Reads the frequency scaling governor of the first cpu
+///
+/// The governor is not supported on this platform, so `None` is returned.
+#[cfg(not(target_os = "linux"))]
+pub fn read_scaling_governor() -> Option<String> {
+    None
+}
@@ -0,0 +1,35 @@
+//! Tracking of sudden changes in the metrics
+//!
+//! A row is highlighted when its value jumps by more than the configured amount between
+//! two updates. The highlight then fades out over the following updates.
+
+/// How much the highlight intensity drops on each update
+///
+/// With this value a highlight fades out completely after four updates.
+const FADE_STEP: f32 = 0.25;
+
+/// The highlight state of a single value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChangeHighlight {
+    /// The value at the previous update
+    ///
+    /// This is `None` until the first value is seen, so the first value is never highlighted.
+    previous: Option<f32>,
+
+    /// How strongly the value is highlighted, between 0 (not at all) and 1
+    pub intensity: f32,
+}
+
+impl ChangeHighlight {
+    /// Records the latest value, highlighting it if it changed by more than `delta`
+    ///
+    /// The highlight of a value that did not change enough fades out a bit instead.
+    pub fn update(&mut self, value: f32, delta: f32) {
+        match self.previous {
+            Some(previous) if (value - previous).abs() > delta => self.intensity = 1.0,
+            _ => self.intensity = (self.intensity - FADE_STEP).max(0.0),
+        }
+
+        self.previous = Some(value);
+    }
+}
@@ -0,0 +1,99 @@
+//! Short histories of the metrics
+//!
+//! The values of a metric are kept for a fixed amount of time, so that they can be
+//! averaged over that time. This gives a stable number for how busy the system has been
+//! recently, which is available on every platform, unlike the load average of the OS.
+//! The trend of the values is used to predict when a resource runs out.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// The values of a single metric over a sliding window of time
+#[derive(Debug, Clone)]
+pub struct MetricHistory {
+    /// How long the values are kept for
+    window: Duration,
+
+    /// The values and when they were recorded, from oldest to newest
+    samples: VecDeque<(Instant, f32)>,
+}
+
+impl MetricHistory {
+    /// Creates an empty history that keeps the values of the last `window`
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Records the latest value, and forgets the values that are older than the window
+    pub fn push(&mut self, value: f32) {
+        let now = Instant::now();
+
+        while let Some((time, _)) = self.samples.front() {
+            if now.duration_since(*time) > self.window {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        self.samples.push_back((now, value));
+    }
+
+    /// Returns the values in the window, from the oldest to the newest
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub fn values(&self) -> impl Iterator<Item = f32> + '_ {
+        self.samples.iter().map(|(_, value)| *value)
+    }
+
+    /// Returns the average of the values in the window
+    ///
+    /// Returns `None` if no values have been recorded yet.
+    pub fn average(&self) -> Option<f32> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let sum: f32 = self.samples.iter().map(|(_, value)| value).sum();
+
+        Some(sum / self.samples.len() as f32)
+    }
+
+    /// Returns how fast the values are changing, in units per second
+    ///
+    /// This is the slope of the least squares line through the values in the window.
+    /// Returns `None` if there are fewer than two values, or they were all recorded at once.
+    pub fn slope_per_second(&self) -> Option<f32> {
+        let (first_time, _) = self.samples.front()?;
+
+        // The times are measured from the oldest value, to keep the numbers small
+        let points: Vec<(f32, f32)> = self
+            .samples
+            .iter()
+            .map(|(time, value)| (time.duration_since(*first_time).as_secs_f32(), *value))
+            .collect();
+
+        let count = points.len() as f32;
+        let mean_time = points.iter().map(|(time, _)| time).sum::<f32>() / count;
+        let mean_value = points.iter().map(|(_, value)| value).sum::<f32>() / count;
+
+        let covariance: f32 = points
+            .iter()
+            .map(|(time, value)| (time - mean_time) * (value - mean_value))
+            .sum();
+        let variance: f32 = points
+            .iter()
+            .map(|(time, _)| (time - mean_time).powi(2))
+            .sum();
+
+        if variance == 0.0 {
+            return None;
+        }
+
+        Some(covariance / variance)
+    }
+}
@@ -13,14 +13,84 @@
 
 #![windows_subsystem = "windows"]
 
+mod alert_sound;
+mod cgroup;
+mod cli;
 mod config;
+mod core_chart;
+mod cpu_times;
+mod custom_metrics;
+mod export;
+mod gauge;
+mod governor;
+mod highlight;
+mod history;
+mod numa;
+mod session;
+mod shortcuts;
+#[cfg(unix)]
+mod sparkline;
+mod style;
+mod swap_activity;
+mod thresholds;
+mod units;
 mod views;
 mod window;
 
+use std::path::Path;
+
 use iced::Application;
 
 fn main() -> iced::Result {
+    let options = cli::LaunchOptions::from_args();
+
+    // The config path has to be set before the config is loaded
+    if let Some(config_path) = &options.config_path {
+        config::Config::set_config_path_override(config_path.clone());
+    }
+
+    let window_settings = if options.meter {
+        // The meter is a small borderless window, meant to be used as a desktop widget
+        iced::window::Settings {
+            size: window::METER_WINDOW_SIZE,
+            decorations: false,
+            resizable: false,
+            ..Default::default()
+        }
+    } else {
+        iced::window::Settings {
+            size: window::DEFAULT_WINDOW_SIZE,
+            ..Default::default()
+        }
+    };
+
+    // The font has to be known before the window is created,
+    // so it is read here instead of waiting for the config to load in the background
+    let default_font = config::Config::read_font().and_then(|font_path| load_font(&font_path));
+
     // Start the application
-    // Default settings are used
-    window::ApplicationWindow::run(iced::Settings::default())
+    // Default settings are used, apart from the window settings and the font
+    // The close request is handled by the application, so the session can be saved first
+    window::ApplicationWindow::run(iced::Settings {
+        window: window_settings,
+        default_font,
+        exit_on_close_request: false,
+        ..iced::Settings::with_flags(options)
+    })
+}
+
+/// Reads a font file for use as the default font
+///
+/// iced needs the font for the whole lifetime of the application, so the bytes are leaked.
+/// Returns `None` if the file could not be read, so the default font is used instead.
+fn load_font(font_path: &Path) -> Option<&'static [u8]> {
+    match std::fs::read(font_path) {
+        Ok(bytes) => Some(Box::leak(bytes.into_boxed_slice())),
+        Err(error) => {
+            eprintln!("Could not read the font {}: {error}", font_path.display());
+            eprintln!("Using the default font instead");
+
+            None
+        }
+    }
 }
@@ -0,0 +1,130 @@
+//! Per-node memory information on NUMA systems
+//!
+//! sysinfo only reports the memory of the whole system, so the per-node numbers are read
+//! from the platform directly. This is currently only supported on Linux, where the
+//! information is exposed in `/sys/devices/system/node`.
+//! On other platforms no nodes are reported, and only the whole system memory is shown.
+
+/// The memory usage of a single NUMA node
+#[derive(Debug, Clone)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub struct NumaNode {
+    /// The id of the node, as given by the platform (0, 1, 2, etc.)
+    pub id: u32,
+
+    /// The total memory of the node in bytes
+    pub total_memory: u64,
+
+    /// The free memory of the node in bytes
+    pub free_memory: u64,
+}
+
+impl NumaNode {
+    /// The used memory of the node in bytes
+    pub fn used_memory(&self) -> u64 {
+        self.total_memory.saturating_sub(self.free_memory)
+    }
+}
+
+/// Returns the memory usage of every NUMA node, sorted by node id
+///
+/// This function reads `/sys/devices/system/node/node*/meminfo`.
+/// An empty vector is returned if the information could not be read.
+#[cfg(target_os = "linux")]
+pub fn get_numa_nodes() -> Vec<NumaNode> {
+    let entries = match std::fs::read_dir("/sys/devices/system/node") {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut nodes: Vec<NumaNode> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+
+            // The node directories are called node0, node1, etc.
+            let id = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("node")?
+                .parse()
+                .ok()?;
+
+            let meminfo = std::fs::read_to_string(entry.path().join("meminfo")).ok()?;
+
+            parse_node_meminfo(id, &meminfo)
+        })
+        .collect();
+
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+/// Returns the memory usage of every NUMA node
+///
+/// Per-node memory is not supported on this platform, so no nodes are returned.
+#[cfg(not(target_os = "linux"))]
+pub fn get_numa_nodes() -> Vec<NumaNode> {
+    Vec::new()
+}
+
+/// Parses the contents of a node's `meminfo` file
+///
+/// The lines of the file look like this:
+///
+/// ```text
+/// Node 0 MemTotal:       16318000 kB
+/// Node 0 MemFree:         9001000 kB
+/// ```
+#[cfg(target_os = "linux")]
+fn parse_node_meminfo(id: u32, meminfo: &str) -> Option<NumaNode> {
+    let mut total_memory = None;
+    let mut free_memory = None;
+
+    for line in meminfo.lines() {
+        // Skip the "Node <id>" prefix
+        let mut fields = line.split_whitespace().skip(2);
+
+        let key = fields.next();
+        let value = fields.next().and_then(|value| value.parse::<u64>().ok());
+
+        // The values are in kibibytes
+        match (key, value) {
+            (Some("MemTotal:"), Some(value)) => total_memory = Some(value * 1024),
+            (Some("MemFree:"), Some(value)) => free_memory = Some(value * 1024),
+            _ => {}
+        }
+    }
+
+    Some(NumaNode {
+        id,
+        total_memory: total_memory?,
+        free_memory: free_memory?,
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_node_meminfo_reads_total_and_free_memory() {
+        let meminfo = "\
+Node 1 MemTotal:       16318000 kB
+Node 1 MemFree:         9001000 kB
+Node 1 MemUsed:         7317000 kB
+";
+
+        let node = parse_node_meminfo(1, meminfo).unwrap();
+
+        assert_eq!(node.id, 1);
+        assert_eq!(node.total_memory, 16318000 * 1024);
+        assert_eq!(node.free_memory, 9001000 * 1024);
+        assert_eq!(node.used_memory(), 7317000 * 1024);
+    }
+
+    #[test]
+    fn parse_node_meminfo_needs_total_and_free_memory() {
+        assert!(parse_node_meminfo(0, "Node 0 MemTotal:       16318000 kB\n").is_none());
+        assert!(parse_node_meminfo(0, "").is_none());
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{self, Config};
+
+/// Struct that stores the state of the user interface between runs of the application.
+///
+/// Unlike the config, the session is not edited by the user. It is saved shortly after
+/// it changes and when the main window is closed, and restored when the application
+/// is started, so that the application opens where the user left off.
+///
+/// The session is stored in `session.toml`, next to the config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionState {
+    /// The index of the page that was open when the window was closed
+    pub page: usize,
+}
+
+impl SessionState {
+    /// Get the path to the session file
+    ///
+    /// The session file is stored in the same directory as the config file.
+    fn get_session_path() -> std::path::PathBuf {
+        Config::get_config_path().with_file_name("session.toml")
+    }
+
+    /// Load the session from disk
+    ///
+    /// This function will load the session from disk and return it.
+    /// If the session file does not exist or could not be read, the default session is returned.
+    pub fn load() -> Self {
+        let session_path = Self::get_session_path();
+
+        // There is nothing to restore on the first run
+        if !session_path.exists() {
+            return Self::default();
+        }
+
+        let session = match std::fs::read_to_string(session_path) {
+            Ok(session) => session,
+            Err(_) => {
+                eprintln!("Could not read session file, starting a new session");
+
+                return Self::default();
+            }
+        };
+
+        match toml::from_str(&session) {
+            Ok(session) => session,
+            Err(_) => {
+                eprintln!("Could not parse session file, starting a new session");
+
+                Self::default()
+            }
+        }
+    }
+
+    /// Save the session to disk
+    ///
+    /// This function will save the session to disk, overwriting the previous session.
+    /// Errors are printed and otherwise ignored, as losing the session is not critical.
+    pub fn save(&self) {
+        // Create the config directory if it does not exist
+        // The error is already printed in the function
+        if Config::ensure_config_dir_exists().is_err() {
+            return;
+        }
+
+        let session_str = match toml::to_string(&self) {
+            Ok(session) => session,
+            Err(_) => {
+                eprintln!("Could not serialize the session");
+
+                return;
+            }
+        };
+
+        if config::write_atomically(&Self::get_session_path(), &session_str).is_err() {
+            eprintln!("Could not write session file");
+            eprintln!("Please check the permissions of the config directory");
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! The keyboard shortcuts of the main window
+//!
+//! The shortcuts are defined in one place, so that the help screen always lists
+//! the same shortcuts that the window responds to.
+
+use iced::{
+    event,
+    keyboard::{self, KeyCode},
+};
+
+use crate::window::ApplicationMessage;
+
+/// A keyboard shortcut, as listed on the help screen
+pub struct Shortcut {
+    /// The keys to press, for example "F2"
+    pub keys: &'static str,
+
+    /// What the shortcut does
+    pub description: &'static str,
+}
+
+/// Every keyboard shortcut of the main window
+///
+/// When adding a shortcut, also handle its key in `shortcut_message`.
+pub const SHORTCUTS: [Shortcut; 3] = [
+    Shortcut {
+        keys: "F2",
+        description: "Switch between the compact view and the full view",
+    },
+    Shortcut {
+        keys: "?",
+        description: "Show or hide this list of shortcuts",
+    },
+    Shortcut {
+        keys: "Esc",
+        description: "Close this list, or the prompt that is open",
+    },
+];
+
+/// Returns the message for a keyboard event, if it is one of the shortcuts
+///
+/// Esc is not handled here, as it is handled by the dialogs themselves.
+/// Keys typed into a text box are captured by it, and are not treated as shortcuts.
+pub fn shortcut_message(
+    event: keyboard::Event,
+    status: event::Status,
+) -> Option<ApplicationMessage> {
+    match event {
+        keyboard::Event::KeyPressed {
+            key_code: KeyCode::F2,
+            ..
+        } => Some(ApplicationMessage::ToggleCompact),
+
+        // The character is used instead of the key, as "?" is on different keys on different layouts
+        keyboard::Event::CharacterReceived('?') if status == event::Status::Ignored => {
+            Some(ApplicationMessage::ToggleHelp)
+        }
+        _ => None,
+    }
+}
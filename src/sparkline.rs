@@ -0,0 +1,92 @@
+//! A small line graph showing how a value changed over time
+//!
+//! The sparkline has no axes or labels, it is meant to be shown next to the current value
+//! to give an idea of its trend.
+
+use iced::{
+    widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke},
+    Point, Rectangle, Theme,
+};
+
+/// The height of a sparkline, in pixels
+const SPARKLINE_HEIGHT: u16 = 40;
+
+/// The width of the line of a sparkline, in pixels
+const LINE_WIDTH: f32 = 2.0;
+
+/// A line graph of a series of values, drawn on a canvas
+struct Sparkline {
+    /// The values to draw, from the oldest to the newest
+    values: Vec<f32>,
+
+    /// The value at the top of the graph
+    ///
+    /// Values above it raise the top of the graph to the highest value.
+    min_max: f32,
+}
+
+impl<Message> canvas::Program<Message> for Sparkline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: canvas::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(bounds.size());
+
+        if self.values.len() < 2 {
+            return vec![frame.into_geometry()];
+        }
+
+        let max = self.values.iter().copied().fold(self.min_max, f32::max);
+
+        // Leave room for the width of the line at the top and bottom
+        let height = bounds.height - LINE_WIDTH;
+        let step = bounds.width / (self.values.len() - 1) as f32;
+
+        let point = |(i, value): (usize, &f32)| {
+            Point::new(
+                i as f32 * step,
+                LINE_WIDTH / 2.0 + height * (1.0 - value / max),
+            )
+        };
+
+        let line = Path::new(|builder| {
+            let mut points = self.values.iter().enumerate().map(point);
+
+            if let Some(first) = points.next() {
+                builder.move_to(first);
+            }
+
+            for next in points {
+                builder.line_to(next);
+            }
+        });
+
+        frame.stroke(
+            &line,
+            Stroke::default()
+                .with_width(LINE_WIDTH)
+                .with_color(theme.extended_palette().primary.base.color),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Returns a sparkline of a series of values, from the oldest to the newest
+///
+/// The graph is scaled to the highest value, but never to less than `min_max`,
+/// so that small changes of a low value do not look like large swings.
+pub fn sparkline<'a, Message: 'a>(values: Vec<f32>, min_max: f32) -> iced::Element<'a, Message> {
+    Canvas::new(Sparkline {
+        values,
+        min_max: min_max.max(f32::EPSILON),
+    })
+    .width(iced::Length::Fill)
+    .height(iced::Length::Units(SPARKLINE_HEIGHT))
+    .into()
+}
@@ -0,0 +1,228 @@
+//! Custom styles for the widgets
+//!
+//! The built-in iced styles do not cover every color the application needs,
+//! so the custom style sheets are defined here.
+
+use iced::{
+    theme,
+    widget::{container, progress_bar},
+    Color, Font, Theme,
+};
+
+use crate::{
+    config::{BarPalette, Config},
+    thresholds::{bar_severity, Severity},
+};
+
+/// Progress bar style for a bar showing a usage percentage
+struct UsageBar {
+    /// The color scheme of the bar
+    palette: BarPalette,
+
+    /// The usage shown by the bar, between 0 and 100
+    percentage: f32,
+
+    /// The severity of the usage, used by the traffic-light palette
+    severity: Severity,
+}
+
+impl progress_bar::StyleSheet for UsageBar {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> progress_bar::Appearance {
+        progress_bar::Appearance {
+            background: style.extended_palette().background.strong.color.into(),
+            bar: usage_color(self.palette, self.percentage, self.severity, style).into(),
+            border_radius: 5.0,
+        }
+    }
+}
+
+/// Returns the color that a usage percentage is drawn in with a bar palette
+///
+/// This is shared by the progress bars and the gauges, so that both follow the palette in the config.
+pub fn usage_color(
+    palette: BarPalette,
+    percentage: f32,
+    severity: Severity,
+    theme: &Theme,
+) -> Color {
+    let theme_palette = theme.extended_palette();
+
+    match palette {
+        BarPalette::Classic => theme_palette.primary.base.color,
+        BarPalette::TrafficLight => severity.color(),
+        BarPalette::Monochrome => theme_palette.background.base.text,
+        BarPalette::Gradient => gradient_color(percentage),
+    }
+}
+
+/// Returns the color of the gradient palette for a usage percentage
+///
+/// The color blends from green at 0% through yellow at 50% to red at 100%.
+fn gradient_color(percentage: f32) -> Color {
+    let t = (percentage / 100.0).clamp(0.0, 1.0);
+
+    if t < 0.5 {
+        // Green to yellow
+        Color::from_rgb(0.2 + 1.4 * t, 0.7, 0.2)
+    } else {
+        // Yellow to red
+        Color::from_rgb(0.9, 0.7 - 1.0 * (t - 0.5), 0.2)
+    }
+}
+
+/// Returns the style of a progress bar showing a usage percentage
+///
+/// The bar is colored according to the bar palette in the config.
+/// With the traffic-light palette it is green, yellow or red depending on the color thresholds.
+pub fn usage_bar_style(percentage: f32, config: &Config) -> theme::ProgressBar {
+    theme::ProgressBar::Custom(Box::new(UsageBar {
+        palette: config.bar_palette,
+        percentage,
+        severity: bar_severity(percentage, config),
+    }))
+}
+
+/// Container style with a translucent background, used to highlight a row
+struct HighlightedRow {
+    /// How strongly the row is highlighted, between 0 (not at all) and 1
+    intensity: f32,
+}
+
+impl container::StyleSheet for HighlightedRow {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        let mut color = style.extended_palette().primary.weak.color;
+        color.a = self.intensity * 0.5;
+
+        container::Appearance {
+            background: Some(color.into()),
+            border_radius: 5.0,
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the style of a row that is highlighted with the given intensity
+///
+/// An intensity of 0 gives a transparent background.
+pub fn highlighted_row_style(intensity: f32) -> theme::Container {
+    theme::Container::Custom(Box::new(HighlightedRow { intensity }))
+}
+
+/// Container style with a solid background in the text color, used to mark a threshold on a bar
+struct ThresholdMarker;
+
+impl container::StyleSheet for ThresholdMarker {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(style.extended_palette().background.base.text.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the style of the marker line drawn at a threshold on a progress bar
+pub fn threshold_marker_style() -> theme::Container {
+    theme::Container::Custom(Box::new(ThresholdMarker))
+}
+
+/// Container style with a faint background in the text color, used to mark a past peak on a bar
+struct PeakMarker;
+
+impl container::StyleSheet for PeakMarker {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        let mut color = style.extended_palette().background.base.text;
+        color.a = 0.35;
+
+        container::Appearance {
+            background: Some(color.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the style of the faint marker line drawn at the highest value seen on a progress bar
+pub fn peak_marker_style() -> theme::Container {
+    theme::Container::Custom(Box::new(PeakMarker))
+}
+
+/// A part of a stacked usage bar
+#[derive(Debug, Clone, Copy)]
+pub enum StackedSegment {
+    /// The time spent running user space code
+    User,
+
+    /// The time spent running kernel code
+    System,
+
+    /// The used memory, in a combined memory and swap bar
+    Memory,
+
+    /// The used swap, in a combined memory and swap bar
+    Swap,
+
+    /// The background of the whole bar
+    Background,
+}
+
+impl container::StyleSheet for StackedSegment {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        let palette = style.extended_palette();
+
+        let (color, border_radius) = match self {
+            StackedSegment::User | StackedSegment::Memory => (palette.primary.base.color, 0.0),
+            StackedSegment::System | StackedSegment::Swap => (palette.danger.base.color, 0.0),
+            StackedSegment::Background => (palette.background.strong.color, 5.0),
+        };
+
+        container::Appearance {
+            background: Some(color.into()),
+            border_radius,
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the style of a part of a stacked usage bar
+pub fn stacked_segment_style(segment: StackedSegment) -> theme::Container {
+    theme::Container::Custom(Box::new(segment))
+}
+
+/// Container style with a thin rounded border, used to group the panels of a page into sections
+struct Section;
+
+impl container::StyleSheet for Section {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: style.extended_palette().background.strong.color,
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the style of a section of a page
+pub fn section_style() -> theme::Container {
+    theme::Container::Custom(Box::new(Section))
+}
+
+/// The monospace font used for the values, so that columns of numbers line up
+///
+/// The font is bundled with the application, so the values are aligned regardless of the
+/// default font. It is DejaVu Sans Mono, see `assets/fonts` for its license.
+pub const MONOSPACE_FONT: Font = Font::External {
+    name: "DejaVu Sans Mono",
+    bytes: include_bytes!("../assets/fonts/DejaVuSansMono.ttf"),
+};
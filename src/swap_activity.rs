@@ -0,0 +1,119 @@
+//! The rate at which pages are swapped in and out
+//!
+//! sysinfo only reports how much swap is used, which does not tell whether the system
+//! is actively thrashing. The number of pages swapped in and out is read from the
+//! platform directly. This is currently only supported on Linux, where the counters
+//! are exposed in `/proc/vmstat`.
+//! On other platforms no rate is reported, and only the swap usage is shown.
+//!
+//! On Linux, swap on a compressed zram device is also detected, from `/proc/swaps`.
+
+use std::time::Duration;
+
+/// The cumulative number of pages swapped in and out since boot
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub struct SwapCounters {
+    /// The number of pages read from swap
+    pub pages_in: u64,
+
+    /// The number of pages written to swap
+    pub pages_out: u64,
+}
+
+/// The number of pages swapped in and out per second between two readings
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwapRate {
+    /// Pages read from swap per second
+    pub pages_in: f32,
+
+    /// Pages written to swap per second
+    pub pages_out: f32,
+}
+
+impl SwapRate {
+    /// Computes the rate between two readings of the counters taken `elapsed` apart
+    ///
+    /// Returns `None` if no time has elapsed between the readings.
+    pub fn between(
+        previous: &SwapCounters,
+        current: &SwapCounters,
+        elapsed: Duration,
+    ) -> Option<Self> {
+        let seconds = elapsed.as_secs_f32();
+
+        if seconds <= 0.0 {
+            return None;
+        }
+
+        let rate = |previous: u64, current: u64| current.saturating_sub(previous) as f32 / seconds;
+
+        Some(Self {
+            pages_in: rate(previous.pages_in, current.pages_in),
+            pages_out: rate(previous.pages_out, current.pages_out),
+        })
+    }
+}
+
+/// Reads the cumulative number of pages swapped in and out
+///
+/// This function reads the `pswpin` and `pswpout` lines of `/proc/vmstat`.
+/// Returns `None` if the counters could not be read.
+#[cfg(target_os = "linux")]
+pub fn read_swap_counters() -> Option<SwapCounters> {
+    let vmstat = std::fs::read_to_string("/proc/vmstat").ok()?;
+
+    let mut pages_in = None;
+    let mut pages_out = None;
+
+    // The lines look like "pswpin 1234"
+    for line in vmstat.lines() {
+        let mut fields = line.split_whitespace();
+
+        let key = fields.next();
+        let value = fields.next().and_then(|value| value.parse::<u64>().ok());
+
+        match (key, value) {
+            (Some("pswpin"), Some(value)) => pages_in = Some(value),
+            (Some("pswpout"), Some(value)) => pages_out = Some(value),
+            _ => {}
+        }
+    }
+
+    Some(SwapCounters {
+        pages_in: pages_in?,
+        pages_out: pages_out?,
+    })
+}
+
+/// Reads the cumulative number of pages swapped in and out
+///
+/// Swap counters are not supported on this platform, so `None` is returned.
+#[cfg(not(target_os = "linux"))]
+pub fn read_swap_counters() -> Option<SwapCounters> {
+    None
+}
+
+/// Returns whether any of the active swap devices is a compressed zram device
+///
+/// This function reads `/proc/swaps`, where the zram devices are listed as `/dev/zram<n>`.
+#[cfg(target_os = "linux")]
+pub fn uses_zram() -> bool {
+    std::fs::read_to_string("/proc/swaps")
+        .map(|swaps| {
+            swaps
+                .lines()
+                // The first line is the header
+                .skip(1)
+                .any(|line| line.starts_with("/dev/zram"))
+        })
+        .unwrap_or(false)
+}
+
+/// Returns whether any of the active swap devices is a compressed zram device
+///
+/// zram only exists on Linux, so `false` is returned.
+#[cfg(not(target_os = "linux"))]
+pub fn uses_zram() -> bool {
+    false
+}
@@ -0,0 +1,243 @@
+//! Evaluation of the warning thresholds of the metrics
+//!
+//! The thresholds themselves are stored in the config, this module decides
+//! whether the current value of a metric exceeds its threshold.
+
+use iced::Color;
+use sysinfo::{ComponentExt, CpuExt, System, SystemExt};
+
+use crate::config::Config;
+
+/// The metrics that have a warning threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// The total cpu usage in percent
+    Cpu,
+
+    /// The memory usage in percent
+    Memory,
+
+    /// The swap usage in percent
+    Swap,
+
+    /// The temperature of the hottest component in degrees celsius
+    Temperature,
+}
+
+impl Metric {
+    /// Every metric that has a warning threshold
+    pub const ALL: [Metric; 4] = [
+        Metric::Cpu,
+        Metric::Memory,
+        Metric::Swap,
+        Metric::Temperature,
+    ];
+
+    /// The name of the metric, as shown in the warning badges
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Cpu => "CPU",
+            Metric::Memory => "Memory",
+            Metric::Swap => "Swap",
+            Metric::Temperature => "Temperature",
+        }
+    }
+
+    /// The unit of the metric's value and threshold
+    pub fn unit(self) -> &'static str {
+        match self {
+            Metric::Temperature => "°C",
+            _ => "%",
+        }
+    }
+
+    /// Returns the current value of the metric
+    ///
+    /// Returns `None` if the value is not available, for example when the system has no swap
+    /// or no temperature sensors.
+    pub fn current_value(self, sys: &System) -> Option<f32> {
+        match self {
+            Metric::Cpu => Some(sys.global_cpu_info().cpu_usage()),
+            Metric::Memory => percentage(sys.used_memory(), sys.total_memory()),
+            Metric::Swap => percentage(sys.used_swap(), sys.total_swap()),
+            Metric::Temperature => sys
+                .components()
+                .iter()
+                .map(|component| component.temperature())
+                .reduce(f32::max),
+        }
+    }
+}
+
+/// How concerning the current value of a metric is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The value is below its threshold
+    Normal,
+
+    /// The value exceeds its threshold
+    Warning,
+
+    /// The value is close to the maximum
+    ///
+    /// This is only used for the colors of the progress bars.
+    Critical,
+}
+
+impl Severity {
+    /// The color used for the warning badges and progress bars of this severity
+    pub fn color(self) -> Color {
+        match self {
+            Severity::Normal => Color::from_rgb(0.2, 0.6, 0.2),
+            Severity::Warning => Color::from_rgb(0.85, 0.55, 0.0),
+            Severity::Critical => Color::from_rgb(0.85, 0.2, 0.2),
+        }
+    }
+}
+
+/// Returns the severity of a metric's value, based on the thresholds in the config
+///
+/// # Example
+///
+/// ```
+/// let config = Config::default(); // The cpu threshold is 90%
+/// threshold_state(Metric::Cpu, 50.0, &config); // Severity::Normal
+/// threshold_state(Metric::Cpu, 95.0, &config); // Severity::Warning
+/// ```
+pub fn threshold_state(metric: Metric, value: f32, config: &Config) -> Severity {
+    let threshold = config.thresholds.get(metric);
+
+    if value > threshold {
+        Severity::Warning
+    } else {
+        Severity::Normal
+    }
+}
+
+/// Detects when the metrics cross their warning thresholds
+///
+/// Only the moment a metric goes above its threshold is reported, not every update
+/// while it stays above it.
+#[derive(Debug, Clone, Default)]
+pub struct ThresholdWatcher {
+    /// The metrics that were above their threshold at the previous update
+    exceeded: Vec<Metric>,
+
+    /// The metrics that were in the critical range at the previous update
+    critical: Vec<Metric>,
+}
+
+/// A metric going above its warning threshold
+#[derive(Debug, Clone, Copy)]
+pub struct ThresholdCrossing {
+    /// The metric that went above its threshold
+    pub metric: Metric,
+
+    /// The value of the metric at the time of the crossing
+    pub value: f32,
+
+    /// The threshold that was exceeded
+    pub threshold: f32,
+}
+
+impl ThresholdWatcher {
+    /// Checks the current value of every enabled metric against its threshold
+    ///
+    /// Returns the metrics that went above their threshold since the previous call.
+    pub fn update(&mut self, sys: &System, config: &Config) -> Vec<ThresholdCrossing> {
+        let mut crossings = Vec::new();
+        let mut exceeded = Vec::new();
+
+        for metric in Metric::ALL {
+            if !config.metrics.includes(metric) {
+                continue;
+            }
+
+            let value = match metric.current_value(sys) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            if threshold_state(metric, value, config) == Severity::Normal {
+                continue;
+            }
+
+            if !self.exceeded.contains(&metric) {
+                crossings.push(ThresholdCrossing {
+                    metric,
+                    value,
+                    threshold: config.thresholds.get(metric),
+                });
+            }
+
+            exceeded.push(metric);
+        }
+
+        self.exceeded = exceeded;
+        crossings
+    }
+
+    /// Checks whether any enabled usage metric went into the critical range since the previous call
+    ///
+    /// The critical range starts where the progress bars turn red. The temperature is
+    /// not a percentage, so it has no critical range and is not checked.
+    pub fn update_critical(&mut self, sys: &System, config: &Config) -> bool {
+        let critical: Vec<Metric> = [Metric::Cpu, Metric::Memory, Metric::Swap]
+            .into_iter()
+            .filter(|metric| config.metrics.includes(*metric))
+            .filter(|metric| {
+                metric.current_value(sys).map_or(false, |value| {
+                    bar_severity(value, config) == Severity::Critical
+                })
+            })
+            .collect();
+
+        let became_critical = critical
+            .iter()
+            .any(|metric| !self.critical.contains(metric));
+
+        self.critical = critical;
+        became_critical
+    }
+}
+
+/// Returns the severity used for the color of a progress bar, based on the color thresholds in the config
+///
+/// # Example
+///
+/// ```
+/// let config = Config::default(); // The bars turn yellow at 60% and red at 85%
+/// bar_severity(50.0, &config); // Severity::Normal
+/// bar_severity(70.0, &config); // Severity::Warning
+/// bar_severity(90.0, &config); // Severity::Critical
+/// ```
+pub fn bar_severity(percentage: f32, config: &Config) -> Severity {
+    if percentage >= config.bar_colors.red {
+        Severity::Critical
+    } else if percentage >= config.bar_colors.yellow {
+        Severity::Warning
+    } else {
+        Severity::Normal
+    }
+}
+
+/// Returns `used` as a percentage of `total`, or `None` if `total` is zero
+pub fn percentage(used: u64, total: u64) -> Option<f32> {
+    if total == 0 {
+        None
+    } else {
+        Some(used as f32 / total as f32 * 100.0)
+    }
+}
+
+/// Returns a percentage that is safe to draw as a progress bar
+///
+/// Values outside of 0 to 100 are clamped, and values that are not a number,
+/// for example from a division by zero, are shown as an empty bar.
+pub fn bar_percentage(value: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(0.0, 100.0)
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,46 @@
+//! Formatting of values with units
+//!
+//! Sizes are formatted by `config::SizeUnit`, rates are formatted here.
+
+/// Formats a transfer rate, picking the prefix that suits the value
+///
+/// # Arguments
+///
+/// * `bytes_per_second` - The rate in bytes per second
+/// * `bits` - Whether to show the rate in bits per second, as network speeds are usually quoted
+/// * `binary` - Whether to use binary prefixes (Ki, Mi, Gi) instead of decimal ones (k, M, G)
+///
+/// # Example
+///
+/// ```
+/// format_rate(1_500_000.0, false, false); // "1.5 MB/s"
+/// format_rate(1_500_000.0, true, false); // "12.0 Mbps"
+/// format_rate(1_048_576.0, false, true); // "1.0 MiB/s"
+/// ```
+pub fn format_rate(bytes_per_second: f64, bits: bool, binary: bool) -> String {
+    let (base, prefixes) = if binary {
+        (1024.0, ["", "Ki", "Mi", "Gi", "Ti"])
+    } else {
+        (1000.0, ["", "k", "M", "G", "T"])
+    };
+
+    let mut value = if bits {
+        bytes_per_second * 8.0
+    } else {
+        bytes_per_second
+    };
+
+    let mut prefix = 0;
+    while value >= base && prefix < prefixes.len() - 1 {
+        value /= base;
+        prefix += 1;
+    }
+
+    let prefix = prefixes[prefix];
+
+    if bits {
+        format!("{value:.1} {prefix}bps")
+    } else {
+        format!("{value:.1} {prefix}B/s")
+    }
+}
@@ -1,8 +1,61 @@
-use crate::window::{ApplicationMessage, ApplicationWindow};
-use bytesize::ByteSize;
-use iced::widget::{column, row, ProgressBar, Text};
+#[cfg(unix)]
+use crate::sparkline::sparkline;
+use crate::{
+    config::{CpuLayout, MemoryBarMode, PercentRounding},
+    core_chart::core_chart,
+    cpu_times::CpuTimesBreakdown,
+    highlight::ChangeHighlight,
+    style::{
+        highlighted_row_style, peak_marker_style, stacked_segment_style, threshold_marker_style,
+        usage_bar_style, StackedSegment, MONOSPACE_FONT,
+    },
+    thresholds::{bar_percentage, percentage, threshold_state, Metric, Severity},
+    views::section::section,
+    window::{ApplicationMessage, ApplicationWindow},
+};
+use iced::widget::{column, container, row, scrollable, ProgressBar, Row, Space, Text};
+use iced_aw::{split::Axis, Split, Wrap};
 
-use sysinfo::{CpuExt, SystemExt};
+use sysinfo::{ComponentExt, CpuExt, SystemExt};
+
+/// How close a component has to be to its critical temperature to be considered near critical
+///
+/// This is a fraction of the critical temperature.
+const THROTTLING_TEMPERATURE_RATIO: f32 = 0.9;
+
+/// How far the cpu frequency has to drop below its highest observed value to be considered throttled
+///
+/// This is a fraction of the highest observed frequency.
+const THROTTLING_FREQUENCY_RATIO: f64 = 0.7;
+
+/// The height of the memory usage bar and its threshold marker, in pixels
+const MEMORY_BAR_HEIGHT: u16 = 30;
+
+/// The height of the cpu usage bars that are built from several widgets, in pixels
+///
+/// This matches the default height of the plain progress bars.
+const CPU_BAR_HEIGHT: u16 = 30;
+
+/// The width of a cpu tile in the wrapped cpu layout, in pixels
+const CPU_TILE_WIDTH: u16 = 150;
+
+/// The most cpus that are shown as separate bands in the stacked cpu chart
+///
+/// Above this the bands get too thin to read, so the chart shows the total usage instead.
+const MAX_CHART_CORES: usize = 16;
+
+/// How fast the memory usage has to grow to show the time-to-full estimate, in percent per second
+///
+/// Below this rate the memory usage is considered stable.
+const MEMORY_GROWTH_THRESHOLD: f32 = 0.01;
+
+/// The color of the label of the cpu with the highest usage
+const BUSIEST_CPU_COLOR: iced::Color = iced::Color {
+    r: 0.25,
+    g: 0.5,
+    b: 0.95,
+    a: 1.0,
+};
 
 // TODO: Make this a seperate struct instead of an impl block
 impl ApplicationWindow {
@@ -10,14 +63,136 @@ impl ApplicationWindow {
     ///
     /// This function returns the home page panel of the main window which contains the system info widgets
     pub fn home_page_view(&self) -> iced::Element<ApplicationMessage> {
-        // Get the cpu usage panel
-        let cpu_usage = self.get_cpu_usage_panel();
-
-        // Get the memory usage widget
-        let memory_usage = self.get_memory_usage_element();
+        let metrics = self.config.metrics;
 
         // Create the main application view
-        column![cpu_usage, memory_usage]
+        let mut home_page = column![];
+
+        // Warnings about the cpu and the metrics that have no panel of their own
+        // are shown above the cpu usage
+        let mut warnings_row = row![].spacing(20);
+        let mut has_warnings = false;
+
+        if metrics.cpu && metrics.components && self.is_thermal_throttling() {
+            warnings_row = warnings_row.push(
+                Text::new("Throttling")
+                    .size(24)
+                    .style(iced::Color::from_rgb(0.85, 0.2, 0.2)),
+            );
+            has_warnings = true;
+        }
+
+        for metric in [Metric::Cpu, Metric::Swap, Metric::Temperature] {
+            if let Some(badge) = self.get_warning_badge(metric) {
+                warnings_row = warnings_row.push(badge);
+                has_warnings = true;
+            }
+        }
+
+        if has_warnings {
+            home_page = home_page.push(warnings_row);
+        }
+
+        // Only show the panels of the metrics that are collected
+        let mut cpu_section = None;
+        let mut memory_section = None;
+
+        if metrics.cpu {
+            // Let the cpu section take up the space left over by the other sections
+            // The header shows the total usage, and its average over the last minute
+            let cpu_usage = self.sys.global_cpu_info().cpu_usage();
+            let mut cpu_title = match self.cpu_history.average() {
+                Some(average) => format!("CPU {cpu_usage:.1}% (1 min: {average:.1}%)"),
+                None => format!("CPU {cpu_usage:.1}%"),
+            };
+
+            // Inside a container, the usage is of the host's cpus, so show how many it may use
+            if let Some(cpu_limit) = self.container_limits.and_then(|limits| limits.cpu_limit) {
+                cpu_title += &format!(", container limit {cpu_limit:.1} cpus");
+            }
+
+            let mut cpu_panel = column![].spacing(10);
+
+            // The governor explains why the frequencies stay low or high, where the platform reports it
+            if let Some(governor) = &self.scaling_governor {
+                cpu_panel = cpu_panel.push(Text::new(format!("Governor: {governor}")));
+            }
+
+            #[cfg(unix)]
+            if self.config.show_load_average {
+                cpu_panel = cpu_panel.push(self.get_load_average_element());
+            }
+
+            // The charts are paused while the system is busy, so they are left out
+            if self.is_reducing_work() {
+                cpu_panel =
+                    cpu_panel.push(Text::new("Updating less often while the system is busy"));
+            } else if self.config.show_core_chart {
+                cpu_panel = cpu_panel.push(self.get_core_chart());
+            }
+
+            cpu_panel = cpu_panel.push(self.get_cpu_usage_panel());
+
+            cpu_section = Some(section(&cpu_title, cpu_panel).height(iced::Length::Fill));
+        }
+
+        if metrics.memory {
+            let mut memory_panel = column![self.get_memory_usage_element()].spacing(10);
+
+            if self.config.show_available_memory {
+                memory_panel = memory_panel.push(self.get_available_memory_element());
+            }
+
+            // Inside a container, the host's memory is shown above, so show the container's as well
+            if let Some(container_memory) = self.get_container_memory_element() {
+                memory_panel = memory_panel.push(container_memory);
+            }
+
+            // The peak is meaningless when the memory usage is not available
+            if self.sys.total_memory() > 0 {
+                memory_panel = memory_panel.push(self.get_peak_memory_element());
+            }
+
+            // Only estimate the time to full while the memory usage is growing
+            if let Some(estimate) = self.get_memory_full_estimate() {
+                memory_panel = memory_panel.push(Text::new(estimate));
+            }
+
+            // Only show the swap activity where the platform reports it
+            if let Some(swap_rate) = &self.swap_rate {
+                memory_panel = memory_panel.push(Text::new(format!(
+                    "Swap activity: {:.0} pages/s in, {:.0} pages/s out",
+                    swap_rate.pages_in, swap_rate.pages_out
+                )));
+            }
+
+            // Only show the per-node memory on systems with more than one NUMA node
+            if self.numa_nodes.len() > 1 {
+                memory_panel = memory_panel.push(self.get_numa_memory_panel());
+            }
+
+            memory_section = Some(section("Memory", memory_panel));
+        }
+
+        match (cpu_section, memory_section) {
+            // The divider between the sections can be dragged to give one of them more space
+            (Some(cpu_section), Some(memory_section)) => {
+                home_page = home_page.push(Split::new(
+                    cpu_section,
+                    memory_section,
+                    self.config.home_split_ratio.map(|ratio| {
+                        (ratio.clamp(0.0, 1.0) * self.get_home_split_height() as f32) as u16
+                    }),
+                    Axis::Horizontal,
+                    ApplicationMessage::HomeSplitResized,
+                ));
+            }
+            (Some(cpu_section), None) => home_page = home_page.push(cpu_section),
+            (None, Some(memory_section)) => home_page = home_page.push(memory_section),
+            (None, None) => {}
+        }
+
+        home_page
             .width(iced::Length::Fill)
             .height(iced::Length::Fill)
             .padding(20)
@@ -26,6 +201,91 @@ impl ApplicationWindow {
             .into()
     }
 
+    /// Wraps a row in a container that highlights it when its value changed suddenly
+    ///
+    /// The row is returned as is if highlighting is disabled in the config.
+    fn highlight_row<'a>(
+        &self,
+        row: iced::Element<'a, ApplicationMessage>,
+        highlight: &ChangeHighlight,
+    ) -> iced::Element<'a, ApplicationMessage> {
+        if !self.config.highlight_changes {
+            return row;
+        }
+
+        container(row)
+            .width(iced::Length::Fill)
+            .padding(5)
+            .style(highlighted_row_style(highlight.intensity))
+            .into()
+    }
+
+    /// Returns the warning badge of a metric, if its value exceeds its threshold
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let badge = self.get_warning_badge(Metric::Cpu);
+    /// // Roughly looks like this when the cpu usage is above the threshold:
+    /// // CPU above 90%
+    /// ```
+    fn get_warning_badge(&self, metric: Metric) -> Option<iced::Element<ApplicationMessage>> {
+        // The value of a metric that is not collected would be stale
+        if !self.config.metrics.includes(metric) {
+            return None;
+        }
+
+        let value = metric.current_value(&self.sys)?;
+
+        match threshold_state(metric, value, &self.config) {
+            Severity::Normal => None,
+            severity => {
+                let threshold = self.config.thresholds.get(metric);
+
+                let badge = Text::new(format!(
+                    "{} above {threshold:.0}{}",
+                    metric.name(),
+                    metric.unit()
+                ))
+                .style(severity.color());
+
+                Some(badge.into())
+            }
+        }
+    }
+
+    /// Returns whether the cpu is likely being thermally throttled
+    ///
+    /// The cpu is considered throttled when a component with a known critical temperature
+    /// is close to that temperature, while the average cpu frequency is well below the
+    /// average of the highest frequencies observed since the application was started.
+    fn is_thermal_throttling(&self) -> bool {
+        let near_critical =
+            self.sys
+                .components()
+                .iter()
+                .any(|component| match component.critical() {
+                    Some(critical) if critical > 0.0 => {
+                        component.temperature() >= critical * THROTTLING_TEMPERATURE_RATIO
+                    }
+                    _ => false,
+                });
+
+        if !near_critical {
+            return false;
+        }
+
+        let current_frequency: u64 = self.sys.cpus().iter().map(|cpu| cpu.frequency()).sum();
+        let max_frequency: u64 = self.max_cpu_frequencies.iter().sum();
+
+        // The frequency is not known on some platforms
+        if max_frequency == 0 {
+            return false;
+        }
+
+        (current_frequency as f64) < (max_frequency as f64) * THROTTLING_FREQUENCY_RATIO
+    }
+
     /// Returns the widget storing the memory usage
     ///
     /// This function returns a row containing the memory usage as a text widget
@@ -40,24 +300,353 @@ impl ApplicationWindow {
     /// ```
     fn get_memory_usage_element(&self) -> iced::Element<ApplicationMessage> {
         // Convert the memory usage to a human readable format
-        let used_memory = ByteSize(self.sys.used_memory());
-        let total_memory = ByteSize(self.sys.total_memory());
+        let used_memory = self.config.size_unit.format(self.sys.used_memory());
+        let total_memory = self.config.size_unit.format(self.sys.total_memory());
+
+        // The memory usage as a percentage, which is not available
+        // when the total memory is zero, as it is in some sandboxes
+        let memory_percentage = Metric::Memory.current_value(&self.sys);
 
         // The memory usage as a text widget
-        let text_widget = Text::new(format!("Memory: {used_memory} / {total_memory}"));
+        let mut text = match memory_percentage {
+            Some(_) => format!("Memory: {used_memory} / {total_memory}"),
+            None => "Memory: no data".to_string(),
+        };
+
+        let combined_bar = self.config.memory_bar == MemoryBarMode::MemoryAndSwap;
+
+        if combined_bar && self.sys.total_swap() > 0 {
+            text += &format!(
+                " + swap {} / {}",
+                self.config.size_unit.format(self.sys.used_swap()),
+                self.config.size_unit.format(self.sys.total_swap())
+            );
+        }
+
+        let text_widget = Text::new(text).font(MONOSPACE_FONT);
+
+        // The memory usage as a progress bar, with a marker at the memory threshold,
+        // or followed by the swap usage
+        let memory_percentage = bar_percentage(memory_percentage.unwrap_or(0.0));
+        let progress_bar = if combined_bar {
+            self.get_memory_and_swap_bar()
+        } else {
+            self.get_memory_bar(memory_percentage)
+        };
 
-        // The memory usage as a progress bar
-        let progress_bar = ProgressBar::new(
-            0.0..=(self.sys.total_memory() as f32),
-            self.sys.used_memory() as f32,
+        let mut memory_row = row![text_widget, progress_bar].spacing(20);
+
+        // Show the warning badge next to the memory usage
+        if let Some(badge) = self.get_warning_badge(Metric::Memory) {
+            memory_row = memory_row.push(badge);
+        }
+
+        self.highlight_row(memory_row.into(), &self.memory_highlight)
+    }
+
+    /// Returns the memory usage bar, with a marker line at the memory warning threshold
+    /// and a faint one at the highest usage since launch
+    ///
+    /// iced has no way to draw over a progress bar, so the bar is split at each marker,
+    /// with the markers between the parts. The parts are sized in proportion to the
+    /// percentage range they cover, so together they look like a single bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let memory_bar = self.get_memory_bar(70.0);
+    /// // Roughly looks like this with the peak at 80% and the threshold at 90%:
+    /// // [===================> : |  ]
+    /// ```
+    fn get_memory_bar(&self, memory_percentage: f32) -> iced::Element<ApplicationMessage> {
+        // Keep every part at least one percent wide
+        let threshold = self.config.thresholds.memory.clamp(1.0, 99.0);
+        let mut markers = vec![(threshold, threshold_marker_style())];
+
+        let peak = bar_percentage(self.peak_memory_percentage);
+        if (1.0..=99.0).contains(&peak) && (peak - threshold).abs() >= 1.0 {
+            markers.push((peak, peak_marker_style()));
+        }
+
+        self.get_marked_bar(memory_percentage, markers, MEMORY_BAR_HEIGHT)
+    }
+
+    /// Returns a progress bar that is split at each marker, with the markers between the parts
+    ///
+    /// # Arguments
+    ///
+    /// * `percentage` - The value of the bar, between 0 and 100
+    /// * `markers` - The position of each marker in percent, with its style.
+    ///   The markers should be at least one percent apart, and from the ends of the bar
+    /// * `height` - The height of the bar and the markers, in pixels
+    fn get_marked_bar(
+        &self,
+        percentage: f32,
+        mut markers: Vec<(f32, iced::theme::Container)>,
+        height: u16,
+    ) -> iced::Element<ApplicationMessage> {
+        markers.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let style = || usage_bar_style(percentage, &self.config);
+
+        // Portions are in tenths of a percent, so that markers close together stay in order
+        let part = |start: f32, end: f32| {
+            ProgressBar::new(start..=end, percentage.clamp(start, end))
+                .width(iced::Length::FillPortion(
+                    ((end - start) * 10.0).round() as u16
+                ))
+                .height(iced::Length::Units(height))
+                .style(style())
+        };
+
+        let mut bar = row![]
+            .width(self.get_bar_width())
+            .spacing(2)
+            .align_items(iced::Alignment::Center);
+        let mut start = 0.0;
+
+        for (position, marker_style) in markers {
+            let marker = container(Space::new(
+                iced::Length::Units(2),
+                iced::Length::Units(height),
+            ))
+            .style(marker_style);
+
+            bar = bar.push(part(start, position)).push(marker);
+            start = position;
+        }
+
+        bar.push(part(start, 100.0)).into()
+    }
+
+    /// Returns the highest memory usage seen since launch
+    ///
+    /// Memory spikes are easy to miss while the window is not watched, so the peak
+    /// is shown below the memory bar, next to the time-to-full estimate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let peak = self.get_peak_memory_element();
+    /// // Peak since start: 81.2%
+    /// ```
+    fn get_peak_memory_element(&self) -> iced::Element<ApplicationMessage> {
+        Text::new(format!(
+            "Peak since start: {:.1}%",
+            self.peak_memory_percentage
+        ))
+        .into()
+    }
+
+    /// Returns a single bar with the memory followed by the swap, to show the total committed memory
+    ///
+    /// The bar is sized in proportion to the memory and swap, with a marker between them.
+    /// The memory fills first, and the swap usage extends beyond it in a different color.
+    /// Systems without swap get just the memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let memory_bar = self.get_memory_and_swap_bar();
+    /// // Roughly looks like this with 16 GiB of memory and 4 GiB of swap:
+    /// // [################   |##    ]
+    /// ```
+    fn get_memory_and_swap_bar(&self) -> iced::Element<ApplicationMessage> {
+        let total = (self.sys.total_memory() + self.sys.total_swap()).max(1) as f64;
+
+        // Portions are in tenths of a percent of the memory and swap together
+        let portion = |bytes: u64| (bytes as f64 / total * 1000.0).round() as u16;
+
+        // A portion of zero does not fill, and would take up the whole bar instead,
+        // so empty segments are left out
+        let segment = |segment: StackedSegment, portion: u16| -> Option<iced::Element<_>> {
+            (portion > 0).then(|| {
+                container(Space::new(
+                    iced::Length::Fill,
+                    iced::Length::Units(MEMORY_BAR_HEIGHT),
+                ))
+                .width(iced::Length::FillPortion(portion))
+                .style(stacked_segment_style(segment))
+                .into()
+            })
+        };
+
+        let free = |portion: u16| -> Option<iced::Element<_>> {
+            (portion > 0).then(|| {
+                Space::new(
+                    iced::Length::FillPortion(portion),
+                    iced::Length::Units(MEMORY_BAR_HEIGHT),
+                )
+                .into()
+            })
+        };
+
+        let used_memory = self.sys.used_memory().min(self.sys.total_memory());
+        let mut segments = vec![
+            segment(StackedSegment::Memory, portion(used_memory)),
+            free(portion(self.sys.total_memory() - used_memory)),
+        ];
+
+        if self.sys.total_swap() > 0 {
+            let used_swap = self.sys.used_swap().min(self.sys.total_swap());
+
+            let marker = container(Space::new(
+                iced::Length::Units(2),
+                iced::Length::Units(MEMORY_BAR_HEIGHT),
+            ))
+            .style(threshold_marker_style());
+
+            segments.push(Some(marker.into()));
+            segments.push(segment(StackedSegment::Swap, portion(used_swap)));
+            segments.push(free(portion(self.sys.total_swap() - used_swap)));
+        }
+
+        let segments = Row::with_children(segments.into_iter().flatten().collect())
+            .align_items(iced::Alignment::Center);
+
+        container(segments)
+            .width(self.get_bar_width())
+            .style(stacked_segment_style(StackedSegment::Background))
+            .into()
+    }
+
+    /// Returns the memory that is available for new programs, as a bar that shrinks as memory is used
+    ///
+    /// The available memory includes caches that can be freed, so it is more than
+    /// the total minus the used memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let available_memory = self.get_available_memory_element();
+    /// // Roughly looks like this:
+    /// // Available: 9.6 GiB / 16.0 GiB [==============>         ]
+    /// ```
+    fn get_available_memory_element(&self) -> iced::Element<ApplicationMessage> {
+        let available_percentage = bar_percentage(
+            percentage(self.sys.available_memory(), self.sys.total_memory()).unwrap_or(0.0),
         );
+
+        let text_widget = Text::new(format!(
+            "Available: {} / {}",
+            self.config.size_unit.format(self.sys.available_memory()),
+            self.config.size_unit.format(self.sys.total_memory())
+        ))
+        .font(MONOSPACE_FONT);
+
+        // The bar is colored by the memory in use, so it turns red as the headroom runs out
+        let progress_bar = ProgressBar::new(0.0..=100.0, available_percentage)
+            .width(self.get_bar_width())
+            .style(usage_bar_style(100.0 - available_percentage, &self.config));
+
         row![text_widget, progress_bar].spacing(20).into()
     }
 
+    /// Returns the memory usage of the container against its limit
+    ///
+    /// Returns `None` when not running in a container with a memory limit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let container_memory = self.get_container_memory_element();
+    /// // Roughly looks like this:
+    /// // Container: 1.2 GiB / 4.0 GiB
+    /// // [=====>                  ]
+    /// ```
+    fn get_container_memory_element(&self) -> Option<iced::Element<ApplicationMessage>> {
+        let limits = self.container_limits?;
+        let memory_limit = limits.memory_limit?;
+        let memory_usage = limits.memory_usage.unwrap_or(0);
+        let usage_percentage =
+            bar_percentage(percentage(memory_usage, memory_limit).unwrap_or(0.0));
+
+        let label = Text::new(format!(
+            "Container: {} / {}",
+            self.config.size_unit.format(memory_usage),
+            self.config.size_unit.format(memory_limit)
+        ));
+
+        let bar = ProgressBar::new(0.0..=100.0, usage_percentage)
+            .style(usage_bar_style(usage_percentage, &self.config));
+
+        Some(column![label, bar].spacing(10).into())
+    }
+
+    /// Returns an estimate of when the memory runs out at the current rate of growth
+    ///
+    /// The rate is the trend of the memory usage over the last minute.
+    /// Returns `None` if the memory usage is stable or shrinking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let estimate = self.get_memory_full_estimate();
+    /// // Some("~4 min to full at current rate")
+    /// ```
+    fn get_memory_full_estimate(&self) -> Option<String> {
+        let growth = self.memory_history.slope_per_second()?;
+
+        if growth < MEMORY_GROWTH_THRESHOLD {
+            return None;
+        }
+
+        let memory_usage = Metric::Memory.current_value(&self.sys)?;
+        let seconds = (100.0 - memory_usage).max(0.0) / growth;
+
+        let estimate = if seconds < 60.0 {
+            format!("~{seconds:.0} s")
+        } else if seconds < 3600.0 {
+            format!("~{:.0} min", seconds / 60.0)
+        } else {
+            format!("~{:.1} h", seconds / 3600.0)
+        };
+
+        Some(format!("{estimate} to full at current rate"))
+    }
+
+    /// Returns the widget storing the memory usage of each NUMA node
+    ///
+    /// This function returns a column containing a row for each NUMA node,
+    /// with the same layout as the memory usage widget
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let numa_memory = self.get_numa_memory_panel();
+    /// // Roughly looks like this:
+    /// // Node 0: 1.00 GiB / 7.79 GiB [=====>                  ]
+    /// // Node 1: 5.20 GiB / 7.79 GiB [===============>        ]
+    /// ```
+    fn get_numa_memory_panel(&self) -> iced::Element<ApplicationMessage> {
+        let mut node_column = column![].spacing(10).width(iced::Length::Fill);
+
+        for node in &self.numa_nodes {
+            // Convert the memory usage to a human readable format
+            let used_memory = self.config.size_unit.format(node.used_memory());
+            let total_memory = self.config.size_unit.format(node.total_memory);
+
+            let text_widget =
+                Text::new(format!("Node {}: {used_memory} / {total_memory}", node.id))
+                    .font(MONOSPACE_FONT);
+
+            let node_percentage =
+                bar_percentage(percentage(node.used_memory(), node.total_memory).unwrap_or(0.0));
+
+            let progress_bar = ProgressBar::new(0.0..=100.0, node_percentage)
+                .style(usage_bar_style(node_percentage, &self.config));
+
+            node_column = node_column.push(row![text_widget, progress_bar].spacing(20));
+        }
+
+        node_column.into()
+    }
+
     /// Returns the widget storing the cpu usage of all CPUs
     ///
-    /// This function returns a column containing the cpu usage of all CPUs
-    /// as a row of text and progress bar widgets
+    /// This function returns a scrollable column containing the cpu usage of all CPUs
+    /// as a row of text and progress bar widgets.
+    /// The column is scrollable so that machines with many cores do not overflow the window.
     ///
     /// # Example
     ///
@@ -70,17 +659,142 @@ impl ApplicationWindow {
     /// // CPU 2: 000.00% [                        ]
     /// // CPU 3: 012.35% [===>                    ]
     /// // CPU 4: 100.00% [========================]
+    ///
+    /// // When the idle cpus are collapsed, they are replaced by a single row:
+    /// // CPU 0: 050.00% [=====================>  ]
+    /// // CPU 1: 100.00% [========================]
+    /// // CPU 3: 012.35% [===>                    ]
+    /// // CPU 4: 100.00% [========================]
+    /// // 1 core idle (below 1%)
     /// ```
     fn get_cpu_usage_panel(&self) -> iced::Element<ApplicationMessage> {
         // The column that will hold the cpu usage
-        let mut cpu_column = column![].spacing(10).width(iced::Length::Fill);
+        // The right padding leaves room for the scrollbar
+        let mut cpu_column = column![]
+            .spacing(10)
+            .padding([0, 20, 0, 0])
+            .width(iced::Length::Fill);
+
+        // The index of the cpu with the highest usage, to make single-threaded bottlenecks easy to spot
+        let busiest_cpu = self
+            .sys
+            .cpus()
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.cpu_usage().total_cmp(&b.cpu_usage()))
+            .map(|(i, _)| i);
+
+        // The temperature of each cpu, if the sensors could be matched to the cores
+        let core_temperatures = if self.config.show_core_temperatures {
+            self.get_core_temperatures()
+        } else {
+            Vec::new()
+        };
+
+        // The number of idle cpus that were left out of the column
+        let mut idle_cpus = 0;
+
+        // The tiles of the cpus in the wrapped layout
+        let mut cpu_tiles = Vec::new();
 
         for (i, cpu) in self.sys.cpus().iter().enumerate() {
+            if self.config.collapse_idle_cores && cpu.cpu_usage() < self.config.idle_core_threshold
+            {
+                idle_cpus += 1;
+                continue;
+            }
+
+            // sysinfo can report a usage that is not a number right after a cpu is added
+            let cpu_usage = bar_percentage(cpu.cpu_usage());
+
             // Push the cpu usage of a single cpu to the column
-            cpu_column = cpu_column.push(self.get_cpu_usage_row(i as i32, cpu.cpu_usage()));
+            match self.config.cpu_layout {
+                CpuLayout::Rows => {
+                    cpu_column = cpu_column.push(self.get_cpu_usage_row(
+                        i as i32,
+                        cpu_usage,
+                        busiest_cpu == Some(i),
+                        core_temperatures.get(i).copied(),
+                    ))
+                }
+                CpuLayout::Wrapped => cpu_tiles.push(self.get_cpu_usage_tile(
+                    i as i32,
+                    cpu_usage,
+                    busiest_cpu == Some(i),
+                )),
+            }
+        }
+
+        if !cpu_tiles.is_empty() {
+            cpu_column = cpu_column.push(Wrap::with_elements(cpu_tiles));
+        }
+
+        // Summarize the idle cpus in a single row
+        if idle_cpus > 0 {
+            let cores = if idle_cpus == 1 { "core" } else { "cores" };
+
+            cpu_column = cpu_column.push(Text::new(format!(
+                "{idle_cpus} {cores} idle (below {:.0}%)",
+                self.config.idle_core_threshold
+            )));
+        }
+
+        // Let the cpu panel take up the space left over by the other panels
+        scrollable(cpu_column).height(iced::Length::Fill).into()
+    }
+
+    /// Returns the load average, with a graph of the 1-minute load average over the last five minutes
+    ///
+    /// Unlike the cpu usage, the load average counts the tasks that are running or waiting
+    /// to run, so it shows when there is more work than the cpus can keep up with.
+    /// The graph's scale goes up to at least two tasks per cpu, so a load of one task per cpu
+    /// reaches at most half its height.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let load_average = self.get_load_average_element();
+    /// // Roughly looks like this:
+    /// // Load average: 0.52 0.61 0.70 [ ___/\__/\___ ]
+    /// ```
+    #[cfg(unix)]
+    fn get_load_average_element(&self) -> iced::Element<ApplicationMessage> {
+        let load_average = self.sys.load_average();
+
+        let label = Text::new(format!(
+            "Load average: {:.2} {:.2} {:.2}",
+            load_average.one, load_average.five, load_average.fifteen
+        ))
+        .font(MONOSPACE_FONT);
+
+        let mut load_row = row![label].spacing(20).align_items(iced::Alignment::Center);
+
+        // The graph is paused while the system is busy, so it is left out
+        if !self.is_reducing_work() {
+            load_row = load_row.push(sparkline(
+                self.load_history.values().collect(),
+                self.sys.cpus().len() as f32 * 2.0,
+            ));
         }
 
-        cpu_column.into()
+        load_row.into()
+    }
+
+    /// Returns the stacked chart of the usage of each cpu over the last updates
+    ///
+    /// On systems with many cpus the bands would be too thin to read,
+    /// so the chart shows a single band with the total usage instead.
+    fn get_core_chart(&self) -> iced::Element<ApplicationMessage> {
+        let samples = if self.cpu_count > MAX_CHART_CORES {
+            self.core_history
+                .iter()
+                .map(|sample| vec![sample.iter().sum::<f32>() / sample.len().max(1) as f32])
+                .collect()
+        } else {
+            self.core_history.iter().cloned().collect()
+        };
+
+        core_chart(samples)
     }
 
     /// Returns the widget storing the cpu usage of a single cpu
@@ -92,28 +806,301 @@ impl ApplicationWindow {
     ///
     /// * `cpu_num` - The number of the cpu (0, 1, 2, etc.)
     /// * `cpu_usage` - The cpu usage of the cpu as a float between 0 and 100
+    /// * `is_busiest` - Whether this is the cpu with the highest usage, which is highlighted
+    /// * `temperature` - The temperature of the core the cpu belongs to, if it is known
     ///
-    /// # Examplewidth
+    /// # Example
     ///
     /// ```
     /// // The comments roughly describe the output
-    /// let cpu_usage = self.get_cpu_usage_row(0, 50.0);   // CPU 0: 050.00% [=====================>  ]
-    /// let cpu_usage = self.get_cpu_usage_row(1, 100.0);  // CPU 1: 100.00% [========================]
-    /// let cpu_usage = self.get_cpu_usage_row(2, 0.0);    // CPU 2: 000.00% [                        ]
-    /// let cpu_usage = self.get_cpu_usage_row(3, 12.345); // CPU 3: 012.35% [===>                    ]
-    /// let cpu_usage = self.get_cpu_usage_row(4, 99.999); // CPU 4: 100.00% [========================]
+    /// let cpu_usage = self.get_cpu_usage_row(0, 50.0, false, None);   // CPU 0: 050.00% [=====================>  ]
+    /// let cpu_usage = self.get_cpu_usage_row(1, 100.0, true, None);   // CPU 1: 100.00% [========================]
+    /// let cpu_usage = self.get_cpu_usage_row(2, 0.0, false, None);    // CPU 2: 000.00% [                        ]
+    /// let cpu_usage = self.get_cpu_usage_row(3, 12.345, false, None); // CPU 3: 012.35% [===>                    ]
+    /// let cpu_usage = self.get_cpu_usage_row(4, 99.999, false, None); // CPU 4: 100.00% [========================]
+    /// // With the floor rounding mode:                              // CPU 4: 099.99% [========================]
+    /// let cpu_usage = self.get_cpu_usage_row(5, 50.0, false, Some(58.0)); // CPU 5: 050.00% 58°C [=============>  ]
     /// ```
-    fn get_cpu_usage_row(&self, cpu_num: i32, cpu_usage: f32) -> iced::Element<ApplicationMessage> {
-        // Progress bar widget storing the cpu usage
-        let progress_bar = ProgressBar::new(0.0..=100.0, cpu_usage);
+    fn get_cpu_usage_row(
+        &self,
+        cpu_num: i32,
+        cpu_usage: f32,
+        is_busiest: bool,
+        temperature: Option<f32>,
+    ) -> iced::Element<ApplicationMessage> {
+        let breakdown = self.cpu_times_breakdown.get(cpu_num as usize);
 
-        // Round the cpu usage to 2 decimal places and left pad to 6 characters
-        // So that the width is always the same
-        let cpu_usage = format!("{:06.2}", cpu_usage);
+        // Only the bar is animated, the label shows the measured usage
+        let bar_usage = self.get_displayed_cpu_usage(cpu_num as usize, cpu_usage);
+
+        // The recent peak of the cpu, if it is far enough above the bar to be told apart
+        let peak = self
+            .cpu_peaks
+            .get(cpu_num as usize)
+            .map(|peak| bar_percentage(*peak))
+            .filter(|peak| self.config.cpu_peak_hold && *peak - bar_usage >= 1.0 && *peak <= 99.0);
+
+        // Progress bar widget storing the cpu usage, split into user and system time if enabled,
+        // or with a marker at the recent peak
+        let progress_bar: iced::Element<_> = match (breakdown, peak) {
+            (Some(breakdown), _) if self.config.stacked_cpu_bars => {
+                self.get_stacked_cpu_bar(breakdown)
+            }
+            (_, Some(peak)) => {
+                self.get_marked_bar(bar_usage, vec![(peak, peak_marker_style())], CPU_BAR_HEIGHT)
+            }
+            _ => ProgressBar::new(0.0..=100.0, bar_usage)
+                .width(self.get_bar_width())
+                .style(usage_bar_style(bar_usage, &self.config))
+                .into(),
+        };
+
+        let cpu_usage = format_cpu_percent(cpu_usage, self.config.percent_rounding);
 
         // Text widget storing the cpu usage
-        let text_widget = Text::new(format!("CPU {cpu_num}: {cpu_usage}%"));
+        let mut text_widget = match temperature {
+            Some(temperature) => {
+                Text::new(format!("CPU {cpu_num}: {cpu_usage}% {temperature:.0}°C"))
+            }
+            None => Text::new(format!("CPU {cpu_num}: {cpu_usage}%")),
+        };
 
-        row![text_widget, progress_bar].spacing(20).into()
+        // Use the monospace font, so the percentages of the cpus line up
+        text_widget = text_widget.font(MONOSPACE_FONT);
+
+        // Color the label of the busiest cpu so it stands out
+        if is_busiest {
+            text_widget = text_widget.style(BUSIEST_CPU_COLOR);
+        }
+
+        let mut cpu_row = row![text_widget, progress_bar].spacing(20);
+
+        // Show the user/system/idle breakdown where the platform provides it
+        if let Some(breakdown) = breakdown {
+            cpu_row = cpu_row.push(
+                Text::new(format!(
+                    "usr {:.0}% sys {:.0}% idle {:.0}%",
+                    breakdown.user, breakdown.system, breakdown.idle
+                ))
+                .font(MONOSPACE_FONT),
+            );
+        }
+
+        match self.cpu_highlights.get(cpu_num as usize) {
+            Some(highlight) => self.highlight_row(cpu_row.into(), highlight),
+            None => cpu_row.into(),
+        }
+    }
+
+    /// Returns the width of the cpu and memory bars, as set in the config
+    fn get_bar_width(&self) -> iced::Length {
+        match self.config.bar_width {
+            Some(width) => iced::Length::Units(width),
+            None => iced::Length::Fill,
+        }
+    }
+
+    /// Returns a small tile with the usage of a single cpu, for the wrapped cpu layout
+    ///
+    /// The tiles have a fixed width, so that they flow into as many columns as fit the window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let cpu_tile = self.get_cpu_usage_tile(3, 12.345, false);
+    /// // Roughly looks like this:
+    /// // CPU 3: 012.35%
+    /// // [==>           ]
+    /// ```
+    fn get_cpu_usage_tile(
+        &self,
+        cpu_num: i32,
+        cpu_usage: f32,
+        is_busiest: bool,
+    ) -> iced::Element<ApplicationMessage> {
+        let mut label = Text::new(format!(
+            "CPU {cpu_num}: {}%",
+            format_cpu_percent(cpu_usage, self.config.percent_rounding)
+        ))
+        .font(MONOSPACE_FONT)
+        .size(16);
+
+        if is_busiest {
+            label = label.style(BUSIEST_CPU_COLOR);
+        }
+
+        // Only the bar is animated, the label shows the measured usage
+        let bar_usage = self.get_displayed_cpu_usage(cpu_num as usize, cpu_usage);
+
+        let progress_bar = ProgressBar::new(0.0..=100.0, bar_usage)
+            .height(iced::Length::Units(12))
+            .style(usage_bar_style(bar_usage, &self.config));
+
+        let tile = container(column![label, progress_bar].spacing(5))
+            .width(iced::Length::Units(CPU_TILE_WIDTH))
+            .padding(5);
+
+        match self.cpu_highlights.get(cpu_num as usize) {
+            Some(highlight) => self.highlight_row(tile.into(), highlight),
+            None => tile.into(),
+        }
+    }
+
+    /// Returns a cpu usage bar split into the time spent in user and system mode
+    ///
+    /// The segments are sized in proportion to their share of the time,
+    /// with the idle time left as the background of the bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let bar = self.get_stacked_cpu_bar(&breakdown);
+    /// // Roughly looks like this with 40% user and 20% system time:
+    /// // [########====          ]
+    /// ```
+    fn get_stacked_cpu_bar(
+        &self,
+        breakdown: &CpuTimesBreakdown,
+    ) -> iced::Element<ApplicationMessage> {
+        // Portions are in tenths of a percent, so small shares are still visible
+        let portion = |percentage: f32| (percentage.clamp(0.0, 100.0) * 10.0).round() as u16;
+
+        let user = portion(breakdown.user);
+        let system = portion(breakdown.system);
+        let idle = 1000u16.saturating_sub(user + system);
+
+        let segment = |segment: StackedSegment, portion: u16| {
+            container(Space::new(
+                iced::Length::Fill,
+                iced::Length::Units(CPU_BAR_HEIGHT),
+            ))
+            .width(iced::Length::FillPortion(portion))
+            .style(stacked_segment_style(segment))
+        };
+
+        // A portion of zero does not fill, and would take up the whole bar instead,
+        // so empty segments are left out
+        let mut segments = row![];
+
+        if user > 0 {
+            segments = segments.push(segment(StackedSegment::User, user));
+        }
+
+        if system > 0 {
+            segments = segments.push(segment(StackedSegment::System, system));
+        }
+
+        // The idle space keeps the bar at its height when the cpu is fully idle
+        if idle > 0 {
+            segments = segments.push(Space::new(
+                iced::Length::FillPortion(idle),
+                iced::Length::Units(CPU_BAR_HEIGHT),
+            ));
+        }
+
+        container(segments)
+            .width(self.get_bar_width())
+            .style(stacked_segment_style(StackedSegment::Background))
+            .into()
+    }
+
+    /// Returns the temperature of the core that each cpu belongs to, in degrees celsius
+    ///
+    /// The sensors are matched to the cores by the "Core <n>" in their labels, as reported
+    /// by the coretemp driver on Linux. When there are fewer sensors than cpus, as with
+    /// hyper-threading, the cpus are assumed to be numbered across the cores first,
+    /// so that cpu `i` belongs to the core `i % <number of cores>`.
+    /// An empty vector is returned if no sensors could be matched to the cores.
+    fn get_core_temperatures(&self) -> Vec<f32> {
+        let mut cores: Vec<(usize, f32)> = self
+            .sys
+            .components()
+            .iter()
+            .filter_map(|component| {
+                let index = core_index(component.label())?;
+
+                Some((index, component.temperature()))
+            })
+            .collect();
+
+        if cores.is_empty() {
+            return Vec::new();
+        }
+
+        // The core numbers are not always contiguous, so only their order is used
+        cores.sort_by_key(|(index, _)| *index);
+
+        (0..self.sys.cpus().len())
+            .map(|i| cores[i % cores.len()].1)
+            .collect()
+    }
+}
+
+/// Returns the core number in the label of a temperature sensor, for example 3 in "coretemp Core 3"
+///
+/// Returns `None` if the label does not name a core.
+fn core_index(label: &str) -> Option<usize> {
+    let (_, number) = label.rsplit_once("Core ")?;
+
+    number.trim().parse().ok()
+}
+
+/// Formats the usage of a cpu for its row on the home page
+///
+/// The usage is rounded to 2 decimal places with the rounding mode from the config,
+/// and left padded to 6 characters so that the width is always the same.
+///
+/// # Example
+///
+/// ```
+/// format_cpu_percent(12.345, PercentRounding::Round); // "012.35"
+/// format_cpu_percent(99.999, PercentRounding::Floor); // "099.99"
+/// ```
+fn format_cpu_percent(cpu_usage: f32, rounding: PercentRounding) -> String {
+    format!("{:06.2}", rounding.apply(cpu_usage, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_cpu_percent_pads_to_six_characters() {
+        assert_eq!(format_cpu_percent(50.0, PercentRounding::Round), "050.00");
+        assert_eq!(format_cpu_percent(0.0, PercentRounding::Round), "000.00");
+        assert_eq!(format_cpu_percent(100.0, PercentRounding::Round), "100.00");
+    }
+
+    #[test]
+    fn format_cpu_percent_rounds_to_two_decimals() {
+        assert_eq!(format_cpu_percent(12.345, PercentRounding::Round), "012.35");
+        assert_eq!(format_cpu_percent(99.999, PercentRounding::Round), "100.00");
+    }
+
+    #[test]
+    fn format_cpu_percent_floors_with_floor_rounding() {
+        assert_eq!(format_cpu_percent(99.999, PercentRounding::Floor), "099.99");
+        assert_eq!(format_cpu_percent(12.345, PercentRounding::Floor), "012.34");
+    }
+
+    #[test]
+    fn format_cpu_percent_keeps_two_decimal_values_with_floor_rounding() {
+        assert_eq!(format_cpu_percent(0.53, PercentRounding::Floor), "000.53");
+        assert_eq!(format_cpu_percent(2.10, PercentRounding::Floor), "002.10");
+        assert_eq!(format_cpu_percent(0.529, PercentRounding::Floor), "000.52");
+    }
+
+    #[test]
+    fn format_cpu_percent_keeps_every_two_decimal_value() {
+        for hundredths in 0..=10_000 {
+            let expected = format!("{:03}.{:02}", hundredths / 100, hundredths % 100);
+            let value: f32 = format!("{}.{:02}", hundredths / 100, hundredths % 100)
+                .parse()
+                .unwrap();
+
+            for rounding in [PercentRounding::Round, PercentRounding::Floor] {
+                assert_eq!(format_cpu_percent(value, rounding), expected);
+            }
+        }
     }
 }
@@ -0,0 +1,52 @@
+use crate::{
+    style::usage_bar_style,
+    thresholds::{bar_percentage, Metric},
+    window::{ApplicationMessage, ApplicationWindow},
+};
+use iced::widget::{column, row, ProgressBar, Text};
+
+use sysinfo::{CpuExt, SystemExt};
+
+impl ApplicationWindow {
+    /// Returns the view of the minimal meter window
+    ///
+    /// This function returns the whole content of the window when the application
+    /// is launched with `--meter`. It only contains the total cpu usage and the memory usage,
+    /// so that it can be used as a small desktop widget.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let meter = self.meter_view();
+    /// // Roughly looks like this:
+    /// // CPU [=========>              ]
+    /// // RAM [================>       ]
+    /// ```
+    pub fn meter_view(&self) -> iced::Element<ApplicationMessage> {
+        // The width of the labels, so that the bars line up
+        let label_width = iced::Length::Units(40);
+
+        let cpu_usage = bar_percentage(self.sys.global_cpu_info().cpu_usage());
+        let cpu_bar = ProgressBar::new(0.0..=100.0, cpu_usage)
+            .height(iced::Length::Units(16))
+            .style(usage_bar_style(cpu_usage, &self.config));
+
+        // Some sandboxes report no memory at all, which is shown as an empty bar
+        let memory_percentage =
+            bar_percentage(Metric::Memory.current_value(&self.sys).unwrap_or(0.0));
+
+        let memory_bar = ProgressBar::new(0.0..=100.0, memory_percentage)
+            .height(iced::Length::Units(16))
+            .style(usage_bar_style(memory_percentage, &self.config));
+
+        column![
+            row![Text::new("CPU").width(label_width), cpu_bar].spacing(10),
+            row![Text::new("RAM").width(label_width), memory_bar].spacing(10),
+        ]
+        .width(iced::Length::Fill)
+        .height(iced::Length::Fill)
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+}
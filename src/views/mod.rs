@@ -1,2 +1,5 @@
 pub mod home;
+pub mod meter;
+pub mod overview;
+pub mod section;
 pub mod settings;
@@ -0,0 +1,324 @@
+use crate::{
+    config::UsageDisplay,
+    gauge::gauge,
+    style::{usage_bar_style, MONOSPACE_FONT},
+    thresholds::{self, percentage, Metric},
+    units::format_rate,
+    views::section::section,
+    window::{ApplicationMessage, ApplicationWindow},
+};
+use iced::widget::{button, column, container, row, ProgressBar, Text};
+
+use sysinfo::{CpuExt, DiskExt, ProcessExt, SystemExt};
+
+impl ApplicationWindow {
+    /// Returns the overview page of the main window
+    ///
+    /// This function returns a compact grid with a small tile for every metric,
+    /// so that the state of the whole system can be seen on one screen.
+    /// The tiles of the metrics that are disabled in the config are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let overview = self.overview_page_view();
+    /// // Roughly looks like this:
+    /// // | CPU                   | Memory           | Swap            |
+    /// // | 12.5% (1 min: 10.2%)  | 3.2 GB / 16.0 GB | 0 B / 2.0 GB    |
+    /// // | [===>      ] | [=====>        ] | [             ] |
+    /// //
+    /// // | Disks            | Network         | Top process  |
+    /// // | 120 GB / 500 GB  | ↓ 1.2 MB/s      | firefox      |
+    /// // | [=====>        ] | ↑ 56.0 kB/s     | 8.3%         |
+    /// ```
+    pub fn overview_page_view(&self) -> iced::Element<ApplicationMessage> {
+        let metrics = self.config.metrics;
+
+        let mut first_row = row![].spacing(20);
+        let mut second_row = row![].spacing(20);
+
+        if metrics.cpu {
+            let cpu_usage = self.sys.global_cpu_info().cpu_usage();
+
+            // Show the average of the last minute next to the current usage
+            let value = match self.cpu_history.average() {
+                Some(average) => format!("{cpu_usage:.1}% (1 min: {average:.1}%)"),
+                None => format!("{cpu_usage:.1}%"),
+            };
+
+            first_row = first_row.push(self.get_overview_tile(
+                "CPU",
+                value,
+                Some(cpu_usage),
+                self.config.usage_display,
+            ));
+        }
+
+        if metrics.memory {
+            first_row = first_row.push(self.get_overview_tile(
+                "Memory",
+                format!(
+                    "{} / {}",
+                    self.config.size_unit.format(self.sys.used_memory()),
+                    self.config.size_unit.format(self.sys.total_memory())
+                ),
+                Metric::Memory.current_value(&self.sys),
+                self.config.usage_display,
+            ));
+
+            first_row = first_row.push(self.get_swap_tile());
+        }
+
+        if metrics.disks {
+            second_row = second_row.push(self.get_disks_tile());
+        }
+
+        if metrics.networks {
+            second_row = second_row.push(self.get_network_tile());
+        }
+
+        if metrics.processes {
+            second_row = second_row.push(self.get_top_process_tile());
+        }
+
+        let mut overview = column![first_row, second_row];
+
+        if !self.config.custom_metrics.is_empty() {
+            overview = overview.push(self.get_custom_metrics_row());
+        }
+
+        if metrics.disks && !self.config.watched_paths.is_empty() {
+            overview = overview.push(self.get_watched_paths_section());
+        }
+
+        // Copy the metrics in a form that can be pasted into bug reports
+        overview = overview
+            .push(button(Text::new("Copy as Markdown")).on_press(ApplicationMessage::CopyMarkdown));
+
+        overview
+            .width(iced::Length::Fill)
+            .height(iced::Length::Fill)
+            .padding(20)
+            .spacing(20)
+            .into()
+    }
+
+    /// Returns a single tile of the overview grid
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The name of the metric
+    /// * `value` - The current value of the metric, already formatted
+    /// * `bar_percentage` - The percentage shown as a progress bar below the value,
+    ///   or `None` if the metric has no bar
+    /// * `display` - Whether the percentage is drawn as a bar or as a gauge
+    fn get_overview_tile(
+        &self,
+        title: &str,
+        value: String,
+        bar_percentage: Option<f32>,
+        display: UsageDisplay,
+    ) -> iced::Element<ApplicationMessage> {
+        let title = Text::new(title.to_string()).size(24);
+
+        let mut tile = column![title, Text::new(value).font(MONOSPACE_FONT)].spacing(10);
+
+        // Values that cannot be drawn, such as from a division by zero, are shown as an empty bar
+        if let Some(bar_percentage) = bar_percentage.map(thresholds::bar_percentage) {
+            tile = match display {
+                UsageDisplay::Bar => tile.push(
+                    ProgressBar::new(0.0..=100.0, bar_percentage)
+                        .height(iced::Length::Units(16))
+                        .style(usage_bar_style(bar_percentage, &self.config)),
+                ),
+                UsageDisplay::Gauge => tile.push(gauge(bar_percentage, &self.config)),
+            };
+        }
+
+        container(tile)
+            .width(iced::Length::Fill)
+            .padding(10)
+            .style(iced::theme::Container::Box)
+            .into()
+    }
+
+    /// Returns the overview tile with the swap usage
+    ///
+    /// Systems without swap get a short note instead of an empty bar.
+    fn get_swap_tile(&self) -> iced::Element<ApplicationMessage> {
+        if self.sys.total_swap() == 0 {
+            return self.get_overview_tile(
+                "Swap",
+                "Not configured".to_string(),
+                None,
+                UsageDisplay::Bar,
+            );
+        }
+
+        let mut swap_value = format!(
+            "{} / {}",
+            self.config.size_unit.format(self.sys.used_swap()),
+            self.config.size_unit.format(self.sys.total_swap())
+        );
+
+        // zram swap is compressed memory, which behaves differently from swap on a disk
+        if self.swap_on_zram {
+            swap_value += " (zram)";
+        }
+
+        // Show whether the swap is actively being used, where the platform reports it
+        if let Some(swap_rate) = &self.swap_rate {
+            swap_value += &format!(
+                "\n{:.0} in / {:.0} out pages/s",
+                swap_rate.pages_in, swap_rate.pages_out
+            );
+        }
+
+        self.get_overview_tile(
+            "Swap",
+            swap_value,
+            Metric::Swap.current_value(&self.sys),
+            UsageDisplay::Bar,
+        )
+    }
+
+    /// Returns the overview tile with the combined usage of all disks
+    fn get_disks_tile(&self) -> iced::Element<ApplicationMessage> {
+        let total_space: u64 = self.sys.disks().iter().map(|disk| disk.total_space()).sum();
+        let available_space: u64 = self
+            .sys
+            .disks()
+            .iter()
+            .map(|disk| disk.available_space())
+            .sum();
+        let used_space = total_space.saturating_sub(available_space);
+
+        self.get_overview_tile(
+            "Disks",
+            format!(
+                "{} / {}",
+                self.config.size_unit.format(used_space),
+                self.config.size_unit.format(total_space)
+            ),
+            percentage(used_space, total_space),
+            UsageDisplay::Bar,
+        )
+    }
+
+    /// Returns the section with the free space of each path in the watch list
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let watched_paths = self.get_watched_paths_section();
+    /// // Roughly looks like this:
+    /// // /mnt/data: 120 GB free of 500 GB [=====>        ]
+    /// // /srv/nfs: not on a known disk
+    /// ```
+    fn get_watched_paths_section(&self) -> iced::Element<ApplicationMessage> {
+        let mut rows = column![].spacing(10);
+
+        for path in &self.config.watched_paths {
+            // The disk a path is on is the one with the longest mount point the path is inside of
+            let disk = self
+                .sys
+                .disks()
+                .iter()
+                .filter(|disk| path.starts_with(disk.mount_point()))
+                .max_by_key(|disk| disk.mount_point().as_os_str().len());
+
+            let path_row: iced::Element<_> = match disk {
+                Some(disk) => {
+                    let used_space = disk.total_space().saturating_sub(disk.available_space());
+                    let used_percentage = thresholds::bar_percentage(
+                        percentage(used_space, disk.total_space()).unwrap_or(0.0),
+                    );
+
+                    row![
+                        Text::new(format!(
+                            "{}: {} free of {}",
+                            path.display(),
+                            self.config.size_unit.format(disk.available_space()),
+                            self.config.size_unit.format(disk.total_space())
+                        )),
+                        ProgressBar::new(0.0..=100.0, used_percentage)
+                            .height(iced::Length::Units(16))
+                            .style(usage_bar_style(used_percentage, &self.config)),
+                    ]
+                    .spacing(20)
+                    .align_items(iced::Alignment::Center)
+                    .into()
+                }
+                None => Text::new(format!("{}: not on a known disk", path.display())).into(),
+            };
+
+            rows = rows.push(path_row);
+        }
+
+        section("Watched paths", rows).into()
+    }
+
+    /// Returns the overview tile with the combined traffic of all network interfaces
+    fn get_network_tile(&self) -> iced::Element<ApplicationMessage> {
+        let (received, transmitted) = self.get_network_rates();
+        let per_second =
+            |bytes_per_second: f64| format_rate(bytes_per_second, self.config.network_bits, false);
+
+        self.get_overview_tile(
+            "Network",
+            format!("↓ {}\n↑ {}", per_second(received), per_second(transmitted)),
+            None,
+            UsageDisplay::Bar,
+        )
+    }
+
+    /// Returns the row with a tile for each custom metric
+    ///
+    /// The values are read in the background, so they are missing until the commands
+    /// have finished for the first time.
+    fn get_custom_metrics_row(&self) -> iced::Element<ApplicationMessage> {
+        let mut custom_row = row![].spacing(20);
+
+        for (i, metric) in self.config.custom_metrics.iter().enumerate() {
+            let tile = match self.custom_metric_values.get(i) {
+                Some(Ok(value)) => self.get_overview_tile(
+                    &metric.name,
+                    format!("{value} {}", metric.unit),
+                    metric
+                        .max
+                        .and_then(|max| (max > 0.0).then(|| value / max * 100.0)),
+                    UsageDisplay::Bar,
+                ),
+                Some(Err(error)) => {
+                    self.get_overview_tile(&metric.name, error.clone(), None, UsageDisplay::Bar)
+                }
+                None => self.get_overview_tile(
+                    &metric.name,
+                    "Waiting for the command".to_string(),
+                    None,
+                    UsageDisplay::Bar,
+                ),
+            };
+
+            custom_row = custom_row.push(tile);
+        }
+
+        custom_row.into()
+    }
+
+    /// Returns the overview tile with the process that uses the most cpu
+    fn get_top_process_tile(&self) -> iced::Element<ApplicationMessage> {
+        let top_process = self
+            .sys
+            .processes()
+            .values()
+            .max_by(|a, b| a.cpu_usage().total_cmp(&b.cpu_usage()));
+
+        let value = match top_process {
+            Some(process) => format!("{}\n{:.1}%", process.name(), process.cpu_usage()),
+            None => "No processes".to_string(),
+        };
+
+        self.get_overview_tile("Top process", value, None, UsageDisplay::Bar)
+    }
+}
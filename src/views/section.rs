@@ -0,0 +1,31 @@
+use iced::widget::{column, container, Container, Text};
+
+use crate::style::section_style;
+
+/// Wraps the content of a panel in a bordered section with a header
+///
+/// This is used to separate the panels of a page, so that they read as distinct sections
+/// instead of one continuous column. The section fills the width of the page,
+/// and its height can be set on the returned container.
+///
+/// # Example
+///
+/// ```
+/// let memory_section = section("Memory", memory_panel);
+/// // Roughly looks like this:
+/// // ---------------------------------------------
+/// // | Memory                                    |
+/// // | Memory: 1.00 GiB / 7.79 GiB [=====>     ] |
+/// // ---------------------------------------------
+/// ```
+pub fn section<'a, Message: 'a>(
+    title: &str,
+    content: impl Into<iced::Element<'a, Message>>,
+) -> Container<'a, Message> {
+    let header = Text::new(title.to_string()).size(24);
+
+    container(column![header, content.into()].spacing(10))
+        .width(iced::Length::Fill)
+        .padding(10)
+        .style(section_style())
+}
@@ -1,9 +1,12 @@
 use crate::{
-    config::Config,
-    window::{ApplicationWindow, MainWindowPage},
+    config::{BarColorThresholds, BarPalette, Config, TabBarPosition},
+    window::{ApplicationMessage, ApplicationWindow, DEFAULT_WINDOW_SIZE},
 };
 
-use iced::widget::{button, column, row, slider, Text};
+use iced::{
+    widget::{button, column, pick_list, row, slider, text_input, Text},
+    Command,
+};
 
 /// Enum for communication inside the settings page
 ///
@@ -17,6 +20,77 @@ pub enum SettingsMessage {
     /// This message is sent to the settings page when the update interval text input is updated.
     UpdateIntervalChanged(f64),
 
+    /// Message to update the update interval from the text box next to the slider
+    ///
+    /// This message is sent to the settings page when the user types in the update interval text box.
+    /// The text is only applied if it is a valid number of seconds.
+    UpdateIntervalText(String),
+
+    /// Message to update the usage percentage at which the bars turn yellow
+    ///
+    /// This message is sent to the settings page when the yellow threshold slider is moved.
+    YellowThresholdChanged(f32),
+
+    /// Message to update the usage percentage at which the bars turn red
+    ///
+    /// This message is sent to the settings page when the red threshold slider is moved.
+    RedThresholdChanged(f32),
+
+    /// Message to update the color scheme of the progress bars
+    ///
+    /// This message is sent to the settings page when a palette is picked from the list.
+    BarPaletteChanged(BarPalette),
+
+    /// Message to update where the tab bar is placed
+    ///
+    /// This message is sent to the settings page when a position is picked from the list.
+    TabBarPositionChanged(TabBarPosition),
+
+    /// Message to reset a single setting to its default
+    ///
+    /// This message is sent to the settings page when the reset button next to a setting is pressed.
+    /// Like the other changes, the default is only applied to the config when the settings are saved.
+    ResetField(SettingsField),
+
+    /// Message to switch to a saved profile
+    ///
+    /// This message is sent to the settings page when a profile is picked from the list.
+    /// The profile replaces the config right away, and unsaved changes are discarded.
+    ProfileSelected(String),
+
+    /// Message to update the name that the settings are saved as a profile under
+    ///
+    /// This message is sent to the settings page when the user types in the profile name text box.
+    ProfileNameChanged(String),
+
+    /// Message to save the current config as a profile
+    ///
+    /// This message is sent to the settings page when the save profile button is pressed.
+    SaveProfile,
+
+    /// Message to export the config to a file
+    ///
+    /// This message is sent to the settings page when the export button is pressed.
+    /// The user picks the file in a save dialog.
+    ExportSettings,
+
+    /// Message with the result of exporting the config
+    ///
+    /// This message is sent when the export has finished, or has failed.
+    SettingsExported(Result<(), String>),
+
+    /// Message to import the config from a file
+    ///
+    /// This message is sent to the settings page when the import button is pressed.
+    /// The user picks the file in an open dialog.
+    ImportSettings,
+
+    /// Message with the config read from an imported file
+    ///
+    /// This message is sent when the file has been read. It contains `None` if the dialog was closed
+    /// without picking a file. Like a profile, a valid config replaces the current one right away.
+    SettingsImported(Result<Option<Config>, String>),
+
     /// Message to save the settings
     ///
     /// This message is sent to the settings page when the save button is pressed.
@@ -34,6 +108,28 @@ pub enum SettingsMessage {
     /// This message is sent to the settings page when the reset button is pressed.
     /// This message should reset the settings to the default settings.
     ResetSettings,
+
+    /// Message to reset the size of the main window
+    ///
+    /// This message is sent to the settings page when the reset window size button is pressed.
+    /// This message should resize the main window back to its default size.
+    ResetWindowSize,
+}
+
+/// The settings that can be reset to their default on their own
+#[derive(Debug, Clone, Copy)]
+pub enum SettingsField {
+    /// The update interval
+    UpdateInterval,
+
+    /// The usage percentages at which the bars turn yellow and red
+    BarColors,
+
+    /// The color scheme of the progress bars
+    BarPalette,
+
+    /// Where the tab bar is placed
+    TabBarPosition,
 }
 
 /// The settings page's state
@@ -44,6 +140,38 @@ pub struct SettingsState {
     /// It is used to update the update interval field when the user types in it.
     /// It is also used to update the config object when the save button is pressed.
     update_interval: u64,
+
+    /// The text in the update interval text box
+    ///
+    /// This is kept separately from `update_interval`, so that partially typed
+    /// or invalid values are not lost while typing.
+    update_interval_text: String,
+
+    /// Whether the text in the update interval text box is not a valid interval
+    update_interval_invalid: bool,
+
+    /// The values of the bar color threshold sliders
+    ///
+    /// The yellow threshold is kept below the red threshold while the sliders are moved.
+    bar_colors: BarColorThresholds,
+
+    /// The palette picked in the bar palette list
+    bar_palette: BarPalette,
+
+    /// The position picked in the tab bar position list
+    tab_bar_position: TabBarPosition,
+
+    /// The names of the saved profiles, read when the settings page is opened
+    profiles: Vec<String>,
+
+    /// The text in the profile name text box
+    profile_name: String,
+
+    /// The error of the last import or export, shown below the import and export buttons
+    file_error: Option<String>,
+
+    /// The error of the last profile that could not be loaded or saved, shown below the profiles row
+    profile_error: Option<String>,
 }
 
 impl SettingsState {
@@ -51,10 +179,257 @@ impl SettingsState {
     pub fn new(config: &Config) -> Self {
         Self {
             update_interval: config.update_interval,
+            update_interval_text: format_interval_seconds(config.update_interval),
+            update_interval_invalid: false,
+            bar_colors: config.bar_colors.clone(),
+            bar_palette: config.bar_palette,
+            tab_bar_position: config.tab_bar_position,
+            profiles: Config::list_profiles(),
+            profile_name: config.current_profile.clone().unwrap_or_default(),
+            file_error: None,
+            profile_error: None,
+        }
+    }
+
+    /// Returns whether the settings page has changes that are not saved to the config
+    pub fn is_dirty(&self, config: &Config) -> bool {
+        self.update_interval != config.update_interval
+            || self.bar_colors != config.bar_colors
+            || self.bar_palette != config.bar_palette
+            || self.tab_bar_position != config.tab_bar_position
+    }
+
+    /// Updates the settings page's state with a message from the settings page
+    ///
+    /// The state is only reachable while the settings page is open, so messages
+    /// from the settings page can never be applied to another page.
+    /// The config is changed and saved when the settings are saved or reset.
+    pub fn update(
+        &mut self,
+        message: &SettingsMessage,
+        config: &mut Config,
+    ) -> Command<ApplicationMessage> {
+        match message {
+            SettingsMessage::UpdateIntervalChanged(value) => {
+                // Value is in seconds, convert to milliseconds
+                self.update_interval = (*value * 1000.0).round() as u64;
+
+                // Keep the text box in sync with the slider
+                self.update_interval_text = format_interval_seconds(self.update_interval);
+                self.update_interval_invalid = false;
+            }
+            SettingsMessage::UpdateIntervalText(text) => {
+                self.update_interval_text = text.clone();
+
+                // Only apply the text if it is a number of seconds in the range of the slider
+                match text.trim().parse::<f64>() {
+                    Ok(seconds) if (0.1..=10.0).contains(&seconds) => {
+                        self.update_interval = (seconds * 1000.0).round() as u64;
+                        self.update_interval_invalid = false;
+                    }
+                    _ => self.update_interval_invalid = true,
+                }
+            }
+            SettingsMessage::YellowThresholdChanged(value) => {
+                // Keep the yellow threshold below the red threshold
+                self.bar_colors.yellow = value.min(self.bar_colors.red - 1.0);
+            }
+            SettingsMessage::RedThresholdChanged(value) => {
+                // Keep the red threshold above the yellow threshold
+                self.bar_colors.red = value.max(self.bar_colors.yellow + 1.0);
+            }
+            SettingsMessage::BarPaletteChanged(palette) => {
+                self.bar_palette = *palette;
+            }
+            SettingsMessage::TabBarPositionChanged(position) => {
+                self.tab_bar_position = *position;
+            }
+            SettingsMessage::ResetField(field) => {
+                let defaults = Config::default();
+
+                match field {
+                    SettingsField::UpdateInterval => {
+                        self.update_interval = defaults.update_interval;
+                        self.update_interval_text = format_interval_seconds(self.update_interval);
+                        self.update_interval_invalid = false;
+                    }
+                    SettingsField::BarColors => self.bar_colors = defaults.bar_colors,
+                    SettingsField::BarPalette => self.bar_palette = defaults.bar_palette,
+                    SettingsField::TabBarPosition => {
+                        self.tab_bar_position = defaults.tab_bar_position
+                    }
+                }
+            }
+            // A profile that cannot be loaded is reported, and the config is left unchanged
+            SettingsMessage::ProfileSelected(name) => match Config::load_profile(name) {
+                Ok(profile) => {
+                    *config = profile;
+                    config.save();
+
+                    *self = Self::new(config);
+                }
+                Err(error) => self.profile_error = Some(error),
+            },
+            SettingsMessage::ProfileNameChanged(name) => {
+                self.profile_name = name.clone();
+            }
+            SettingsMessage::SaveProfile => {
+                // The name is saved without surrounding spaces, so that is the name that is checked
+                let name = self.profile_name.trim().to_string();
+
+                if !Config::is_valid_profile_name(&name) {
+                    self.profile_error = Some(format!("{name} is not a valid profile name"));
+
+                    return Command::none();
+                }
+
+                // The profile is saved with the settings on the page, like the save button does
+                config.update_interval = self.update_interval;
+                config.bar_colors = self.bar_colors.clone();
+                config.bar_palette = self.bar_palette;
+                config.tab_bar_position = self.tab_bar_position;
+                config.current_profile = Some(name.clone());
+                config.save();
+
+                self.profile_error = config
+                    .save_profile(&name)
+                    .err()
+                    .map(|error| format!("Could not save the profile {name}: {error}"));
+
+                self.profiles = Config::list_profiles();
+            }
+            SettingsMessage::ExportSettings => {
+                let config = config.clone();
+
+                return Command::perform(
+                    async move {
+                        let file = rfd::AsyncFileDialog::new()
+                            .add_filter("Config file", &["toml"])
+                            .set_file_name("icy-sysmonitor.toml")
+                            .save_file()
+                            .await;
+
+                        match file {
+                            Some(file) => config.export_file(file.path().to_path_buf()).await,
+                            None => Ok(()),
+                        }
+                    },
+                    |result| {
+                        ApplicationMessage::SettingsPageUpdated(SettingsMessage::SettingsExported(
+                            result,
+                        ))
+                    },
+                );
+            }
+            SettingsMessage::SettingsExported(result) => {
+                self.file_error = result.clone().err();
+            }
+            SettingsMessage::ImportSettings => {
+                return Command::perform(
+                    async {
+                        let file = rfd::AsyncFileDialog::new()
+                            .add_filter("Config file", &["toml"])
+                            .pick_file()
+                            .await;
+
+                        match file {
+                            Some(file) => Config::import_file(file.path().to_path_buf())
+                                .await
+                                .map(Some),
+                            None => Ok(None),
+                        }
+                    },
+                    |result| {
+                        ApplicationMessage::SettingsPageUpdated(SettingsMessage::SettingsImported(
+                            result,
+                        ))
+                    },
+                );
+            }
+            SettingsMessage::SettingsImported(result) => match result {
+                Ok(Some(imported)) => {
+                    *config = imported.clone();
+                    config.save();
+
+                    *self = Self::new(config);
+                }
+                Ok(None) => {}
+                Err(error) => self.file_error = Some(error.clone()),
+            },
+            SettingsMessage::SaveSettings => {
+                config.update_interval = self.update_interval;
+                config.bar_colors = self.bar_colors.clone();
+                config.bar_palette = self.bar_palette;
+                config.tab_bar_position = self.tab_bar_position;
+                config.save();
+            }
+            SettingsMessage::CancelSettings => {
+                self.update_interval = config.update_interval;
+                self.update_interval_text = format_interval_seconds(self.update_interval);
+                self.update_interval_invalid = false;
+                self.bar_colors = config.bar_colors.clone();
+                self.bar_palette = config.bar_palette;
+                self.tab_bar_position = config.tab_bar_position;
+            }
+            SettingsMessage::ResetSettings => {
+                *config = Config::default();
+                config.save();
+
+                // This will update the settings page to show the default settings
+                // As the fields are copied from the config when canceling the settings
+                return self.update(&SettingsMessage::CancelSettings, config);
+            }
+            SettingsMessage::ResetWindowSize => {
+                let (width, height) = DEFAULT_WINDOW_SIZE;
+
+                return iced::window::resize(width, height);
+            }
         }
+
+        Command::none()
     }
 }
 
+/// A common update interval, listed next to the update interval slider for quick selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IntervalPreset(u64);
+
+impl IntervalPreset {
+    /// Every preset, in milliseconds
+    const ALL: [IntervalPreset; 4] = [
+        IntervalPreset(500),
+        IntervalPreset(1000),
+        IntervalPreset(2000),
+        IntervalPreset(5000),
+    ];
+}
+
+impl std::fmt::Display for IntervalPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} s", format_interval_seconds(self.0))
+    }
+}
+
+/// Formats an update interval in milliseconds as seconds, for the update interval text box
+///
+/// # Example
+///
+/// ```
+/// format_interval_seconds(1000); // "1"
+/// format_interval_seconds(250); // "0.25"
+/// ```
+fn format_interval_seconds(milliseconds: u64) -> String {
+    (milliseconds as f64 / 1000.0).to_string()
+}
+
+/// Returns a small button that resets a single setting to its default
+fn reset_field_button<'a>(field: SettingsField) -> iced::Element<'a, SettingsMessage> {
+    button(Text::new("Reset").size(16))
+        .style(iced::theme::Button::Secondary)
+        .on_press(SettingsMessage::ResetField(field))
+        .into()
+}
+
 /// TODO: Seperate the settings page into a different struct instead of an impl block
 impl ApplicationWindow {
     /// Returns the settings page view of the main window
@@ -64,10 +439,41 @@ impl ApplicationWindow {
         // The update interval row
         let update_interval_row = self.get_update_interval_row(state);
 
+        // The bar color thresholds rows
+        let bar_colors_rows = self.get_bar_colors_rows(state);
+
+        // The bar palette row
+        let bar_palette_row = self.get_bar_palette_row(state);
+
+        // The tab bar position row
+        let tab_bar_position_row = self.get_tab_bar_position_row(state);
+
+        // The profiles row
+        let profiles_row = self.get_profiles_row(state);
+
+        // The import and export row
+        let file_row = self.get_settings_file_row(state);
+
         // The buttons row
-        let buttons_row = self.get_settings_page_buttons_row();
+        let buttons_row = self.get_settings_page_buttons_row(state);
+
+        let mut settings_page = column![
+            title,
+            update_interval_row,
+            bar_colors_rows,
+            bar_palette_row,
+            tab_bar_position_row,
+            profiles_row,
+            file_row,
+            buttons_row
+        ];
+
+        // The resources used by the monitor itself
+        if let Some(own_usage) = self.get_own_usage() {
+            settings_page = settings_page.push(Text::new(own_usage).size(16));
+        }
 
-        column![title, update_interval_row, buttons_row]
+        settings_page
             .width(iced::Length::Fill)
             .height(iced::Length::Fill)
             .padding(20)
@@ -75,45 +481,6 @@ impl ApplicationWindow {
             .align_items(iced::Alignment::Center)
             .into()
     }
-
-    pub fn settings_page_update(&mut self, message: &SettingsMessage) {
-        let state = match &mut self.page {
-            MainWindowPage::Settings(state) => state,
-            _ => {
-                // TODO: Make it show a dialog instead of printing to the terminal
-                eprintln!(
-                    "ApplicationMessage::SettingsPageUpdated was sent when the settings page \
-                        was not open, this should not happen!"
-                );
-                eprintln!("Please report this bug at https://github.com/DitherWither/icy-sysmonitor/issues");
-                eprintln!("Continuing as if nothing happened...");
-                return;
-            }
-        };
-
-        match message {
-            SettingsMessage::UpdateIntervalChanged(value) => {
-                // Value is in seconds, convert to milliseconds
-                state.update_interval = (*value * 1000.0) as u64;
-            }
-            SettingsMessage::SaveSettings => {
-                self.config.update_interval = state.update_interval;
-                self.config.save();
-            }
-            SettingsMessage::CancelSettings => {
-                state.update_interval = self.config.update_interval;
-            }
-            SettingsMessage::ResetSettings => {
-                self.config = Config::default();
-                self.config.save();
-
-                // This will update the settings page to show the default settings
-                // As the config is reloaded when canceling the settings
-                // TODO: This is a bit hacky, maybe find a better way to do this
-                self.settings_page_update(&SettingsMessage::CancelSettings);
-            }
-        }
-    }
 }
 
 /// The widgets used in the settings page
@@ -121,19 +488,27 @@ impl ApplicationWindow {
     /// Returns the button row for saving and canceling the settings
     ///
     /// This function returns the row that contains the save and cancel buttons.
-    /// The save button should save the settings to disk.
+    /// The save button should save the settings to disk, and is disabled when nothing has changed.
     /// The cancel button should discard the changes to the settings.
     ///
     /// # Example
     ///
     /// ```
-    /// let buttons_row = self.get_settings_page_buttons_row();
+    /// let buttons_row = self.get_settings_page_buttons_row(state);
     /// // This roughly looks like this:
-    /// // [Cancel] [Save settings]
+    /// // [Save] [Cancel] [Reset to Default] [Reset Window Size]
     /// ```
-    fn get_settings_page_buttons_row(&self) -> iced::Element<SettingsMessage> {
+    fn get_settings_page_buttons_row(
+        &self,
+        state: &SettingsState,
+    ) -> iced::Element<SettingsMessage> {
         // Button to save the settings
-        let save_button = button(Text::new("Save")).on_press(SettingsMessage::SaveSettings);
+        // The button is disabled when there is nothing to save
+        let mut save_button = button(Text::new("Save"));
+
+        if state.is_dirty(&self.config) {
+            save_button = save_button.on_press(SettingsMessage::SaveSettings);
+        }
 
         // Button to cancel the changes to the settings
         // TODO: Make the cancel button red
@@ -144,9 +519,18 @@ impl ApplicationWindow {
         let reset_button =
             button(Text::new("Reset to Default")).on_press(SettingsMessage::ResetSettings);
 
-        row![save_button, cancel_button, reset_button]
-            .spacing(10)
-            .into()
+        // Button to resize the window back to its default size
+        let reset_window_size_button =
+            button(Text::new("Reset Window Size")).on_press(SettingsMessage::ResetWindowSize);
+
+        row![
+            save_button,
+            cancel_button,
+            reset_button,
+            reset_window_size_button
+        ]
+        .spacing(10)
+        .into()
     }
 
     /// Returns the row that contains the update interval input slider and the label
@@ -160,7 +544,7 @@ impl ApplicationWindow {
     /// ```
     /// let update_interval_row = self.get_update_interval_row();
     /// // This roughly looks like this:
-    /// // Update interval: [-||-----------] 1.0 seconds
+    /// // Update interval: [-||-----------] [1    ] seconds [1 s v] [Reset]
     fn get_update_interval_row(&self, state: &SettingsState) -> iced::Element<SettingsMessage> {
         // Title for the update interval input slider
         let update_interval_title = Text::new("Update interval");
@@ -176,20 +560,227 @@ impl ApplicationWindow {
         )
         .step(0.1);
 
-        // Text label that shows the current value of the update interval input slider
-        let update_interval_value_label = Text::new(format!(
-            "{:.1} seconds",
-            state.update_interval as f64 / 1000.0
-        ));
+        // Text box to type an exact update interval
+        let update_interval_text_input = text_input(
+            "seconds",
+            &state.update_interval_text,
+            SettingsMessage::UpdateIntervalText,
+        )
+        .width(iced::Length::Units(80));
+
+        // List of common intervals, which sends the same message as the slider
+        // Nothing is picked in the list when the interval is not one of the presets
+        let update_interval_presets = pick_list(
+            &IntervalPreset::ALL[..],
+            IntervalPreset::ALL
+                .into_iter()
+                .find(|preset| preset.0 == state.update_interval),
+            |preset: IntervalPreset| {
+                SettingsMessage::UpdateIntervalChanged(preset.0 as f64 / 1000.0)
+            },
+        )
+        .placeholder("Presets");
+
+        // Text label that shows the unit, or a hint when the typed value is invalid
+        let update_interval_value_label = if state.update_interval_invalid {
+            Text::new("seconds (0.1 to 10)").style(iced::Color::from_rgb(0.85, 0.2, 0.2))
+        } else {
+            Text::new("seconds")
+        };
 
-        // The row that contains the update interval input slider and the label
+        // The row that contains the update interval input slider, the text box and the label
         let update_interval_row = row![
             update_interval_title,
             update_interval_input,
-            update_interval_value_label
+            update_interval_text_input,
+            update_interval_value_label,
+            update_interval_presets,
+            reset_field_button(SettingsField::UpdateInterval)
         ]
-        .spacing(10);
+        .spacing(10)
+        .align_items(iced::Alignment::Center);
 
         update_interval_row.into()
     }
+
+    /// Returns the rows that contain the bar color threshold sliders and their labels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let bar_colors_rows = self.get_bar_colors_rows(state);
+    /// // This roughly looks like this:
+    /// // Yellow bars from [------||-----] 60%
+    /// // Red bars from    [---------||--] 85%
+    /// // [Reset]
+    /// ```
+    fn get_bar_colors_rows(&self, state: &SettingsState) -> iced::Element<SettingsMessage> {
+        let yellow_row = row![
+            Text::new("Yellow bars from"),
+            slider(
+                0.0..=100.0,
+                state.bar_colors.yellow,
+                SettingsMessage::YellowThresholdChanged
+            )
+            .step(1.0),
+            Text::new(format!("{:.0}%", state.bar_colors.yellow)),
+        ]
+        .spacing(10);
+
+        let red_row = row![
+            Text::new("Red bars from"),
+            slider(
+                0.0..=100.0,
+                state.bar_colors.red,
+                SettingsMessage::RedThresholdChanged
+            )
+            .step(1.0),
+            Text::new(format!("{:.0}%", state.bar_colors.red)),
+        ]
+        .spacing(10);
+
+        column![
+            yellow_row,
+            red_row,
+            reset_field_button(SettingsField::BarColors)
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    /// Returns the row that contains the list to pick the color scheme of the progress bars
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let bar_palette_row = self.get_bar_palette_row(state);
+    /// // This roughly looks like this:
+    /// // Bar colors [Traffic-light v] [Reset]
+    /// ```
+    fn get_bar_palette_row(&self, state: &SettingsState) -> iced::Element<SettingsMessage> {
+        let palette_list = pick_list(
+            &BarPalette::ALL[..],
+            Some(state.bar_palette),
+            SettingsMessage::BarPaletteChanged,
+        );
+
+        row![
+            Text::new("Bar colors"),
+            palette_list,
+            reset_field_button(SettingsField::BarPalette)
+        ]
+        .spacing(10)
+        .align_items(iced::Alignment::Center)
+        .into()
+    }
+
+    /// Returns the row that contains the list to pick where the tab bar is placed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tab_bar_position_row = self.get_tab_bar_position_row(state);
+    /// // This roughly looks like this:
+    /// // Tab bar position [Top v] [Reset]
+    /// ```
+    fn get_tab_bar_position_row(&self, state: &SettingsState) -> iced::Element<SettingsMessage> {
+        let position_list = pick_list(
+            &TabBarPosition::ALL[..],
+            Some(state.tab_bar_position),
+            SettingsMessage::TabBarPositionChanged,
+        );
+
+        row![
+            Text::new("Tab bar position"),
+            position_list,
+            reset_field_button(SettingsField::TabBarPosition)
+        ]
+        .spacing(10)
+        .align_items(iced::Alignment::Center)
+        .into()
+    }
+
+    /// Returns the row to switch between saved profiles and to save the settings as a profile
+    ///
+    /// The error of the last profile that could not be loaded or saved is shown below the row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let profiles_row = self.get_profiles_row(state);
+    /// // This roughly looks like this:
+    /// // Profile [Workstation v] [Laptop battery] [Save as profile]
+    /// ```
+    fn get_profiles_row(&self, state: &SettingsState) -> iced::Element<SettingsMessage> {
+        let profile_list = pick_list(
+            state.profiles.clone(),
+            self.config.current_profile.clone(),
+            SettingsMessage::ProfileSelected,
+        )
+        .placeholder("No profiles");
+
+        let profile_name_input = text_input(
+            "Profile name",
+            &state.profile_name,
+            SettingsMessage::ProfileNameChanged,
+        )
+        .width(iced::Length::Units(200));
+
+        // The button is disabled until a usable name is typed
+        let mut save_profile_button = button(Text::new("Save as profile"));
+
+        if Config::is_valid_profile_name(state.profile_name.trim()) {
+            save_profile_button = save_profile_button.on_press(SettingsMessage::SaveProfile);
+        }
+
+        let profiles_row = row![
+            Text::new("Profile"),
+            profile_list,
+            profile_name_input,
+            save_profile_button
+        ]
+        .spacing(10)
+        .align_items(iced::Alignment::Center);
+
+        let mut profiles_column = column![profiles_row]
+            .spacing(10)
+            .align_items(iced::Alignment::Center);
+
+        if let Some(error) = &state.profile_error {
+            profiles_column =
+                profiles_column.push(Text::new(error).style(iced::Color::from_rgb(0.85, 0.2, 0.2)));
+        }
+
+        profiles_column.into()
+    }
+
+    /// Returns the row to export the config to a file and to import it from one
+    ///
+    /// The error of the last import or export is shown below the buttons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let file_row = self.get_settings_file_row(state);
+    /// // This roughly looks like this:
+    /// // [Export to file] [Import from file]
+    /// // settings.toml is not a valid config file: ...
+    /// ```
+    fn get_settings_file_row(&self, state: &SettingsState) -> iced::Element<SettingsMessage> {
+        let buttons = row![
+            button(Text::new("Export to file")).on_press(SettingsMessage::ExportSettings),
+            button(Text::new("Import from file")).on_press(SettingsMessage::ImportSettings),
+        ]
+        .spacing(10);
+
+        let mut file_row = column![buttons]
+            .spacing(10)
+            .align_items(iced::Alignment::Center);
+
+        if let Some(error) = &state.file_error {
+            file_row = file_row.push(Text::new(error).style(iced::Color::from_rgb(0.85, 0.2, 0.2)));
+        }
+
+        file_row.into()
+    }
 }
@@ -1,17 +1,90 @@
 use iced::{
-    time,
-    widget::{column, Text},
-    Application, Command,
+    subscription, theme, time,
+    widget::{button, column, container, row, Text},
+    Application, Command, Subscription,
 };
-use iced_aw::{Icon, TabBar, TabLabel};
-use std::time::Duration;
-use sysinfo::{System, SystemExt};
+use iced_aw::{Card, Icon, Modal, TabBar, TabLabel, ICON_FONT};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+use sysinfo::{CpuExt, NetworkExt, NetworksExt, ProcessExt, System, SystemExt};
 
 use crate::{
-    config,
+    alert_sound::{self, ALERT_SOUND_COOLDOWN},
+    cgroup::{self, ContainerLimits},
+    cli::LaunchOptions,
+    config::{self, ExportMode, NavigationLayout, TabBarPosition},
+    cpu_times::{self, CpuTimes, CpuTimesBreakdown},
+    custom_metrics, export, governor,
+    highlight::ChangeHighlight,
+    history::MetricHistory,
+    numa::{self, NumaNode},
+    session::SessionState,
+    shortcuts::{self, SHORTCUTS},
+    style::MONOSPACE_FONT,
+    swap_activity::{self, SwapCounters, SwapRate},
+    thresholds::{bar_percentage, Metric, ThresholdCrossing, ThresholdWatcher},
+    units::format_rate,
     views::settings::{SettingsMessage, SettingsState},
 };
 
+/// The size of the main window when the application is started, in pixels
+///
+/// The window can be resized back to this size from the settings page.
+pub const DEFAULT_WINDOW_SIZE: (u32, u32) = (1024, 768);
+
+/// The size of the window when the application is launched with `--meter`, in pixels
+pub const METER_WINDOW_SIZE: (u32, u32) = (240, 80);
+
+/// The shortest interval of the update and temperature timers, in milliseconds
+///
+/// The intervals can be edited by hand in the config file, and a timer with
+/// an interval of zero would panic.
+const MIN_TIMER_INTERVAL: u64 = 100;
+
+/// How long the total cpu usage is averaged over for the recent average
+const CPU_AVERAGE_WINDOW: Duration = Duration::from_secs(60);
+
+/// How often the config file is checked for changes made outside of the application
+const CONFIG_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long the session has to stay unchanged before it is saved
+///
+/// Switching pages quickly changes the session several times,
+/// so it is only saved once the changes stop.
+const SESSION_SAVE_DELAY: Duration = Duration::from_secs(2);
+
+/// The time to wait after the divider on the home page was last dragged before saving the config
+///
+/// Dragging the divider changes the config many times a second,
+/// so it is only saved once the divider stops moving.
+const SPLIT_SAVE_DELAY: Duration = Duration::from_secs(2);
+
+/// The average cpu usage over the last minute above which the system is considered busy, in percent
+const BUSY_CPU_THRESHOLD: f32 = 90.0;
+
+/// How much longer the update interval is while the system is busy
+const BUSY_INTERVAL_FACTOR: u64 = 2;
+
+/// How often the animated bars move towards their new values
+const ANIMATION_FRAME_INTERVAL: Duration = Duration::from_millis(33);
+
+/// How close an animated bar has to be to its value for the animation to stop, in percent
+const ANIMATION_SETTLE_DISTANCE: f32 = 0.1;
+
+/// How fast the peak-hold markers of the cpu bars fall back, in percent per second
+const CPU_PEAK_DECAY: f32 = 10.0;
+
+/// The number of updates kept for the stacked cpu chart
+const CORE_CHART_SAMPLES: usize = 60;
+
+/// How far back the load average graph goes
+const LOAD_AVERAGE_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// How far back the memory usage trend is measured, for the time-to-full estimate
+const MEMORY_TREND_WINDOW: Duration = Duration::from_secs(60);
+
 /// The application struct that implements the Application trait
 ///
 /// This struct contains all the data that is needed to run the application
@@ -47,6 +120,167 @@ pub struct ApplicationWindow {
     /// The settings can be changed by the user in the settings page.
     /// Should be loaded in the new function
     pub config: config::Config,
+
+    /// The memory usage of each NUMA node
+    ///
+    /// This is empty on platforms where per-node memory is not available,
+    /// and is updated together with the rest of the system info.
+    pub numa_nodes: Vec<NumaNode>,
+
+    /// The number of cpus at the last update
+    ///
+    /// The state kept for each cpu is reset when this changes.
+    pub cpu_count: usize,
+
+    /// The highest frequency observed for each cpu, in MHz
+    ///
+    /// This is used to detect thermal throttling, as sysinfo does not
+    /// report the maximum frequency of the cpus.
+    pub max_cpu_frequencies: Vec<u64>,
+
+    /// The cumulative user/system/idle times of each cpu at the last update
+    ///
+    /// This is empty on platforms where the cpu times are not available.
+    pub cpu_times: Vec<CpuTimes>,
+
+    /// The share of time each cpu spent in user, system and idle mode since the previous update
+    ///
+    /// This is empty until two readings of the cpu times have been taken.
+    pub cpu_times_breakdown: Vec<CpuTimesBreakdown>,
+
+    /// The total cpu usage over the last minute
+    ///
+    /// This is averaged to show how busy the cpu has been recently.
+    pub cpu_history: MetricHistory,
+
+    /// The highlight state of each cpu's usage
+    ///
+    /// A cpu row is highlighted when its usage changes suddenly.
+    pub cpu_highlights: Vec<ChangeHighlight>,
+
+    /// The recent peak usage of each cpu, for the peak-hold markers
+    ///
+    /// A peak falls back slowly once the usage drops below it.
+    pub cpu_peaks: Vec<f32>,
+
+    /// The highlight state of the memory usage
+    pub memory_highlight: ChangeHighlight,
+
+    /// The usage of each cpu at each of the last updates, for the stacked cpu chart
+    ///
+    /// This is only recorded while the chart is enabled in the config.
+    pub core_history: VecDeque<Vec<f32>>,
+
+    /// The usage of each cpu as currently drawn by the animated bars
+    ///
+    /// Each frame moves these values towards the usage of the last update.
+    pub displayed_cpu_usage: Vec<f32>,
+
+    /// The frequency scaling governor of the cpus, for example "powersave"
+    ///
+    /// This is `None` on platforms where the governor cannot be read.
+    pub scaling_governor: Option<String>,
+
+    /// The 1-minute load average over the last five minutes, for the load average graph
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub load_history: MetricHistory,
+
+    /// The memory usage in percent over the last minute
+    ///
+    /// The trend of the usage is used to estimate when the memory runs out.
+    pub memory_history: MetricHistory,
+
+    /// The highest memory usage in percent since launch
+    ///
+    /// This makes memory spikes visible that happened while the window was not watched.
+    pub peak_memory_percentage: f32,
+
+    /// The swap counters at the last update, and when they were read
+    ///
+    /// This is `None` on platforms where the swap counters are not available.
+    pub swap_counters: Option<(SwapCounters, Instant)>,
+
+    /// The number of pages swapped in and out per second since the previous update
+    ///
+    /// This is `None` until two readings of the swap counters have been taken.
+    pub swap_rate: Option<SwapRate>,
+
+    /// Whether the swap is on a compressed zram device
+    ///
+    /// This is read once in the new function, as the swap devices are set up at boot,
+    /// and reading them again on every update would add a file read to each tick.
+    pub swap_on_zram: bool,
+
+    /// Detects the metrics going above their thresholds, for the events-only export mode
+    pub threshold_watcher: ThresholdWatcher,
+
+    /// The memory and cpu limits of the container the application runs in
+    ///
+    /// This is `None` when not running in a container, or on platforms where the limits
+    /// cannot be read.
+    pub container_limits: Option<ContainerLimits>,
+
+    /// When the alert sound was last played, or `None` if it has not been played yet
+    pub alert_sound_played: Option<Instant>,
+
+    /// The model name of the cpu, for example "AMD Ryzen 7 5800X"
+    ///
+    /// This is read once in the new function, as it does not change.
+    pub cpu_brand: String,
+
+    /// The size of the main window, in pixels
+    ///
+    /// The divider on the home page is stored as a share of the window,
+    /// so the size is needed to place it.
+    pub window_size: (u32, u32),
+
+    /// The number of times the divider on the home page was dragged,
+    /// used to save the config only once the divider stops moving
+    pub split_changes: u64,
+
+    /// The latest values of the custom metrics, in the same order as in the config
+    ///
+    /// A metric whose command failed has an error message instead of a value.
+    pub custom_metric_values: Vec<Result<f32, String>>,
+
+    /// Whether the custom metric commands are running
+    ///
+    /// The commands are not started again until the previous run has finished.
+    pub reading_custom_metrics: bool,
+
+    /// Whether the config file has been loaded
+    ///
+    /// Until then the default settings are shown, and the config is not saved,
+    /// so that the defaults are not written over the config file.
+    pub config_loaded: bool,
+
+    /// The time the config file was last modified, to notice when it is edited by hand
+    pub config_modified: Option<std::time::SystemTime>,
+
+    /// The number of times the session has changed, used to save it only once the changes stop
+    pub session_changes: u64,
+
+    /// Whether the prompt to confirm closing the window is shown
+    pub quit_requested: bool,
+
+    /// Whether the list of keyboard shortcuts is shown
+    pub show_help: bool,
+
+    /// The page the user tried to switch to while the settings page had unsaved changes
+    ///
+    /// A prompt to discard the changes is shown while this is set.
+    pub pending_page: Option<usize>,
+
+    /// Whether the main window currently has focus
+    ///
+    /// The system info is updated less often while the window is not focused,
+    /// if enabled in the config.
+    pub is_focused: bool,
+
+    /// Whether the application was launched as the minimal meter window
+    ///
+    /// The meter window only shows the total cpu usage and the memory usage.
+    pub meter_mode: bool,
 }
 
 /// The message enum for the application to communicate with itself
@@ -62,40 +296,161 @@ pub enum ApplicationMessage {
 
     TabSelected(usize),
 
+    /// ApplicationMessage when the divider between the sections of the home page is dragged
+    HomeSplitResized(u16),
+
+    /// ApplicationMessage to save the position of the divider on the home page
+    ///
+    /// This message is sent to the application some time after the divider was dragged.
+    /// It carries the number of times the divider was dragged at that time, so that saves
+    /// from before a later drag can be ignored.
+    SaveHomeSplit(u64),
+
+    /// ApplicationMessage when the main window is resized, with its new width and height
+    WindowResized(u32, u32),
+
     /// ApplicationMessage when the settings page is updated
     ///
     /// This message is sent to the application when the settings page is updated.
     /// This message should be handled by the settings page's update function.
     SettingsPageUpdated(SettingsMessage),
+
+    /// ApplicationMessage with the values of the custom metrics
+    ///
+    /// This message is sent to the application when the custom metric commands have finished.
+    /// The values are in the same order as the custom metrics in the config.
+    CustomMetricsRead(Vec<Result<f32, String>>),
+
+    /// ApplicationMessage to check whether the config file was changed outside of the application
+    ///
+    /// This message is sent to the application on a slow timer.
+    CheckConfigFile,
+
+    /// ApplicationMessage with the config that was read after the config file changed
+    ///
+    /// This message is sent to the application when the changed config file has been read.
+    /// It contains an error if the file could not be read or parsed.
+    ConfigFileChanged(Result<config::Config, String>),
+
+    /// ApplicationMessage to save the session, after it has not changed for a while
+    ///
+    /// This message is sent to the application some time after the session changed.
+    /// It carries the number of session changes at that time, so that saves from before
+    /// a later change can be ignored.
+    SaveSession(u64),
+
+    /// ApplicationMessage to refresh the temperature sensors
+    ///
+    /// This message is sent to the application on a slower timer than `UpdateInfo`,
+    /// as the sensors change slowly and can be expensive to read.
+    RefreshTemps,
+
+    /// ApplicationMessage to copy the current metrics to the clipboard as Markdown tables
+    ///
+    /// This message is sent to the application when the copy button on the overview page is pressed.
+    CopyMarkdown,
+
+    /// ApplicationMessage to move the animated bars towards their new values
+    ///
+    /// This message is sent to the application many times a second while the bars are moving.
+    AnimationFrame,
+
+    /// ApplicationMessage to write a snapshot of the system info to disk
+    ///
+    /// This message is sent to the application on the schedule set in the config,
+    /// if scheduled exports are enabled.
+    ScheduledExport,
+
+    /// ApplicationMessage when the user asks to close the main window
+    ///
+    /// This message is sent to the application instead of closing the window directly,
+    /// so that the session can be saved before the application exits.
+    CloseRequested,
+
+    /// ApplicationMessage when the main window gains or loses focus
+    ///
+    /// This message is sent to the application so that the update interval can be
+    /// throttled while the window is in the background.
+    FocusChanged(bool),
+
+    /// ApplicationMessage when the config file has been loaded at startup
+    ///
+    /// The config is loaded in the background, and the default settings are used until
+    /// this message is received.
+    ConfigLoaded(config::Config),
+
+    /// ApplicationMessage to switch between the compact view and the full view
+    ///
+    /// This message is sent to the application when F2 is pressed.
+    ToggleCompact,
+
+    /// ApplicationMessage to close the window after the user confirmed it
+    ///
+    /// This message is sent from the prompt shown when closing the window with `confirm_on_quit` enabled.
+    ConfirmQuit,
+
+    /// ApplicationMessage to keep the window open
+    ///
+    /// This message is sent from the prompt shown when closing the window with `confirm_on_quit` enabled.
+    CancelQuit,
+
+    /// ApplicationMessage to show or hide the list of keyboard shortcuts
+    ///
+    /// This message is sent to the application when ? is typed, or when the list is closed.
+    ToggleHelp,
+
+    /// ApplicationMessage to discard the unsaved settings and switch to the page the user picked
+    ///
+    /// This message is sent from the prompt shown when leaving the settings page with unsaved changes.
+    DiscardSettings,
+
+    /// ApplicationMessage to stay on the settings page and keep the unsaved changes
+    ///
+    /// This message is sent from the prompt shown when leaving the settings page with unsaved changes.
+    KeepEditingSettings,
 }
 
 /// The enum for the pages of the main window of the application
 ///
 /// This enum is used to store the current page of the main window.
-/// The main window currently only has two pages: the home page and the settings page.
+/// The main window currently has three pages: the home page, the overview page and the settings page.
 pub enum MainWindowPage {
     /// The home page of the main window
     ///
     /// This page currently contains the system info widgets and the settings button
     Home,
 
+    /// The overview page of the main window
+    ///
+    /// This page contains a compact tile for every metric
+    Overview,
+
     /// The settings page of the main window
     Settings(SettingsState),
 }
 
 impl MainWindowPage {
+    /// The icon and label of every page, in the order they are shown in the navigation
+    ///
+    /// The position of a page in this array is its index.
+    const LABELS: [(Icon, &'static str); 3] = [
+        (Icon::House, "Home"),
+        (Icon::Grid, "Overview"),
+        (Icon::Gear, "Settings"),
+    ];
+
     fn to_index(&self) -> usize {
         match self {
             MainWindowPage::Home => 0,
-            MainWindowPage::Settings(_) => 1,
+            MainWindowPage::Overview => 1,
+            MainWindowPage::Settings(_) => 2,
         }
     }
-    fn from_index(index: usize) -> Option<Self> {
+    fn from_index(index: usize, config: &config::Config) -> Option<Self> {
         match index {
             0 => Some(MainWindowPage::Home),
-            1 => Some(MainWindowPage::Settings(SettingsState::new(
-                &config::Config::load(),
-            ))),
+            1 => Some(MainWindowPage::Overview),
+            2 => Some(MainWindowPage::Settings(SettingsState::new(config))),
             _ => None,
         }
     }
@@ -105,51 +460,318 @@ impl MainWindowPage {
 impl Application for ApplicationWindow {
     type Executor = iced::executor::Default;
     type Theme = iced::theme::Theme; // TODO: Add dark theme
-    type Flags = ();
+    type Flags = LaunchOptions;
     type Message = ApplicationMessage;
 
-    fn new(_flags: ()) -> (Self, iced::Command<Self::Message>) {
+    fn new(flags: LaunchOptions) -> (Self, iced::Command<Self::Message>) {
+        // Create a new system object to get system info
+        let sys = System::new_all();
+
+        // The model name is the same for every cpu
+        let cpu_brand = sys
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string())
+            .unwrap_or_default();
+
+        let cpu_count = sys.cpus().len();
+
+        // The state of the user interface when the application was last closed
+        let session = SessionState::load();
+
+        // Show the default settings until the config file is loaded,
+        // so that a slow home directory does not stall the startup
+        let config = config::Config::default();
+
         (
             Self {
-                // Create a new system object to get system info
-                sys: System::new_all(),
+                // Use the system object to get system info
+                sys,
+
+                // Restore the page that was open when the application was last closed
+                page: MainWindowPage::from_index(session.page, &config)
+                    .unwrap_or(MainWindowPage::Home),
+
+                window_size: DEFAULT_WINDOW_SIZE,
+                split_changes: 0,
 
-                // Set the current page to the home page
-                page: MainWindowPage::Home,
+                config,
 
-                // Load the config file
-                config: config::Config::load(),
+                // Read the memory usage of the NUMA nodes
+                numa_nodes: numa::get_numa_nodes(),
+
+                cpu_count,
+
+                // Filled in on every update
+                max_cpu_frequencies: Vec::new(),
+
+                // Take the first reading of the cpu times, the breakdown needs two readings
+                cpu_times: cpu_times::read_cpu_times().unwrap_or_default(),
+                cpu_times_breakdown: Vec::new(),
+
+                cpu_history: MetricHistory::new(CPU_AVERAGE_WINDOW),
+
+                // Nothing is highlighted until the values start changing
+                cpu_highlights: Vec::new(),
+                cpu_peaks: Vec::new(),
+                memory_highlight: ChangeHighlight::default(),
+
+                memory_history: MetricHistory::new(MEMORY_TREND_WINDOW),
+                peak_memory_percentage: 0.0,
+                load_history: MetricHistory::new(LOAD_AVERAGE_WINDOW),
+                scaling_governor: governor::read_scaling_governor(),
+                core_history: VecDeque::new(),
+                displayed_cpu_usage: Vec::new(),
+
+                // Take the first reading of the swap counters, the rate needs two readings
+                swap_counters: swap_activity::read_swap_counters()
+                    .map(|counters| (counters, Instant::now())),
+                swap_rate: None,
+                swap_on_zram: swap_activity::uses_zram(),
+
+                threshold_watcher: ThresholdWatcher::default(),
+                alert_sound_played: None,
+                container_limits: cgroup::read_container_limits(),
+
+                cpu_brand,
+
+                pending_page: None,
+                show_help: false,
+                quit_requested: false,
+                session_changes: 0,
+                config_loaded: false,
+                config_modified: config::Config::get_modified_time(),
+                custom_metric_values: Vec::new(),
+                reading_custom_metrics: false,
+
+                // Windows are focused when they are opened
+                is_focused: true,
+
+                meter_mode: flags.meter,
             },
-            // Return a command to do nothing as we don't need to do anything else
-            Command::none(),
+            // Load the config file in the background
+            Command::perform(
+                config::Config::load_async(),
+                ApplicationMessage::ConfigLoaded,
+            ),
         )
     }
 
     fn title(&self) -> String {
-        "Icy System Monitor".to_string()
+        // iced calls this on every redraw, so the live stats stay up to date
+        if self.config.live_title {
+            format!("Icy System Monitor — {}", self.get_metrics_summary())
+        } else {
+            "Icy System Monitor".to_string()
+        }
     }
 
     fn update(&mut self, message: ApplicationMessage) -> iced::Command<ApplicationMessage> {
         match message {
             // Update the system info
             ApplicationMessage::UpdateInfo => {
-                self.sys.refresh_all();
+                self.refresh_system_info();
+
+                // Run the custom metric commands in the background,
+                // unless the previous run has not finished yet
+                if !self.config.custom_metrics.is_empty() && !self.reading_custom_metrics {
+                    self.reading_custom_metrics = true;
+
+                    return Command::perform(
+                        custom_metrics::read_custom_metrics(self.config.custom_metrics.clone()),
+                        ApplicationMessage::CustomMetricsRead,
+                    );
+                }
+            }
+
+            ApplicationMessage::CustomMetricsRead(values) => {
+                self.custom_metric_values = values;
+                self.reading_custom_metrics = false;
+            }
+
+            // Update the temperature sensors
+            ApplicationMessage::RefreshTemps => {
+                if self.config.metrics.components {
+                    self.sys.refresh_components();
+                }
+            }
+
+            ApplicationMessage::AnimationFrame => self.animate_bars(),
+
+            ApplicationMessage::CopyMarkdown => {
+                return iced::clipboard::write(export::snapshot_markdown(
+                    &self.sys,
+                    self.config.size_unit,
+                    self.config.metrics.processes,
+                ));
             }
 
             ApplicationMessage::TabSelected(index) => {
-                match MainWindowPage::from_index(index) {
-                    Some(page) => self.page = page,
-                    None => {
-                        // TODO: Create a error page instead of printing the error
-                        eprintln!("Invalid page index: {index}");
-                        eprintln!("How did you even get here?");
-                        eprintln!("Please report this bug on GitHub: https://github.com/DitherWither/icy-sysmonitor/issues")
+                // Ask before discarding the unsaved changes on the settings page
+                if let MainWindowPage::Settings(state) = &self.page {
+                    if index != self.page.to_index() && state.is_dirty(&self.config) {
+                        self.pending_page = Some(index);
+
+                        return Command::none();
                     }
                 }
+
+                self.select_page(index);
+
+                return self.schedule_session_save();
+            }
+
+            // The position is saved in the config, so it is ignored until the config is loaded
+            ApplicationMessage::HomeSplitResized(position) => {
+                if !self.config_loaded {
+                    return Command::none();
+                }
+
+                let ratio = position as f32 / self.get_home_split_height() as f32;
+                self.config.home_split_ratio = Some(ratio.clamp(0.0, 1.0));
+
+                self.split_changes += 1;
+                let change_count = self.split_changes;
+
+                return Command::perform(tokio::time::sleep(SPLIT_SAVE_DELAY), move |_| {
+                    ApplicationMessage::SaveHomeSplit(change_count)
+                });
+            }
+
+            // Only save the config if the divider has not moved again since the save was scheduled
+            // The application's own save is not an outside edit, so the file is not reloaded
+            ApplicationMessage::SaveHomeSplit(change_count) => {
+                if change_count == self.split_changes {
+                    self.config.save();
+                    self.config_modified = config::Config::get_modified_time();
+                }
+            }
+
+            ApplicationMessage::WindowResized(width, height) => self.window_size = (width, height),
+
+            ApplicationMessage::DiscardSettings => {
+                if let Some(index) = self.pending_page.take() {
+                    self.select_page(index);
+
+                    return self.schedule_session_save();
+                }
+            }
+
+            // Reload the config when the file changed, for example when it was edited by hand
+            ApplicationMessage::CheckConfigFile => {
+                let modified = config::Config::get_modified_time();
+
+                if modified != self.config_modified {
+                    self.config_modified = modified;
+
+                    return Command::perform(
+                        config::Config::reload_async(),
+                        ApplicationMessage::ConfigFileChanged,
+                    );
+                }
+            }
+
+            // The update interval is the setting that is changed most often, and only needs
+            // the timer to change, which happens when the subscription is rebuilt.
+            // Other changes are applied like a freshly loaded config.
+            // A file that cannot be parsed or has an invalid value, such as a hand edit
+            // that is not finished yet, is ignored, so that the current config is kept and not saved over the file.
+            ApplicationMessage::ConfigFileChanged(config) => match config {
+                Ok(config) if self.config.matches_apart_from_update_interval(&config) => {
+                    refresh_clean_settings_page(&mut self.page, &self.config, &config);
+                    self.config.update_interval = config.update_interval;
+                }
+                Ok(config) => return self.update(ApplicationMessage::ConfigLoaded(config)),
+
+                // TODO: Make this display a dialog instead of printing to stderr
+                Err(error) => {
+                    eprintln!("{error}");
+                    eprintln!("Keeping the current settings until the config file is fixed");
+                }
+            },
+
+            // Only save the session if it has not changed again since the save was scheduled
+            ApplicationMessage::SaveSession(change_count) => {
+                if change_count == self.session_changes {
+                    self.get_session_state().save();
+                }
             }
 
+            ApplicationMessage::KeepEditingSettings => self.pending_page = None,
+
+            ApplicationMessage::ToggleHelp => self.show_help = !self.show_help,
+
             // Update the settings page
-            ApplicationMessage::SettingsPageUpdated(message) => self.settings_page_update(&message),
+            // A message that arrives after switching to another page is out of date, and is dropped
+            // The settings page is rebuilt once the config is loaded, so changes from before that
+            // would be lost anyway, and saving them would write the defaults over the config file
+            ApplicationMessage::SettingsPageUpdated(message) => {
+                if !self.config_loaded {
+                    return Command::none();
+                }
+
+                if let MainWindowPage::Settings(state) = &mut self.page {
+                    return state.update(&message, &mut self.config);
+                }
+            }
+
+            // Write a snapshot of the system info
+            ApplicationMessage::ScheduledExport => self.write_scheduled_export(),
+
+            ApplicationMessage::FocusChanged(is_focused) => self.is_focused = is_focused,
+
+            // Apply the loaded config
+            ApplicationMessage::ConfigLoaded(config) => {
+                refresh_clean_settings_page(&mut self.page, &self.config, &config);
+
+                self.config = config;
+                self.config_loaded = true;
+
+                // Restore the size of the compact view, the window is opened at the default size
+                if self.config.compact_mode && !self.meter_mode {
+                    let (width, height) = METER_WINDOW_SIZE;
+
+                    return iced::window::resize(width, height);
+                }
+            }
+
+            // Switch the view and resize the window to fit it
+            // The setting is saved, so it is ignored until the config is loaded
+            // The meter window always shows the meter, so it has nothing to switch
+            ApplicationMessage::ToggleCompact => {
+                if !self.config_loaded || self.meter_mode {
+                    return Command::none();
+                }
+
+                self.config.compact_mode = !self.config.compact_mode;
+                self.config.save();
+
+                let (width, height) = if self.config.compact_mode {
+                    METER_WINDOW_SIZE
+                } else {
+                    DEFAULT_WINDOW_SIZE
+                };
+
+                return iced::window::resize(width, height);
+            }
+
+            // Save the session and close the window
+            ApplicationMessage::CloseRequested => {
+                // The prompt does not fit in the compact view or the meter window
+                let can_prompt = !self.meter_mode && !self.config.compact_mode;
+
+                if self.config.confirm_on_quit && can_prompt && !self.quit_requested {
+                    self.quit_requested = true;
+
+                    return Command::none();
+                }
+
+                return self.quit();
+            }
+
+            ApplicationMessage::ConfirmQuit => return self.quit(),
+
+            ApplicationMessage::CancelQuit => self.quit_requested = false,
         }
 
         // Return a command to do nothing as we don't need to do anything else
@@ -157,69 +779,982 @@ impl Application for ApplicationWindow {
     }
 
     fn view(&self) -> iced::Element<ApplicationMessage> {
-        // The header of the main window
-        let header = self.get_header();
+        // The meter window and the compact view have no pages or navigation
+        if self.meter_mode || self.config.compact_mode {
+            return self.meter_view();
+        }
 
         // The main content of the main window
         let main_content = match &self.page {
             MainWindowPage::Home => self.home_page_view(),
+            MainWindowPage::Overview => self.overview_page_view(),
             MainWindowPage::Settings(state) => self.settings_page_view(state).map(|message| {
                 // Map the message to the application's message
                 ApplicationMessage::SettingsPageUpdated(message)
             }),
         };
 
-        // Create the main window
-        column![header, main_content]
-            .width(iced::Length::Fill)
-            .height(iced::Length::Fill)
-            .padding(20)
-            .spacing(20)
-            .align_items(iced::Alignment::Center)
+        let window_content: iced::Element<ApplicationMessage> = match self.config.navigation {
+            NavigationLayout::TabBar => {
+                // The header of the main window, which contains the tab bar
+                let header = self.get_header();
+
+                let mut window_column = column![header, main_content];
+
+                // The tab bar is below the page when it is placed at the bottom
+                if self.config.tab_bar_position == TabBarPosition::Bottom {
+                    window_column = window_column.push(self.get_navigation_row());
+                }
+
+                // Create the main window
+                window_column
+                    .width(iced::Length::Fill)
+                    .height(iced::Length::Fill)
+                    .padding(20)
+                    .spacing(20)
+                    .align_items(iced::Alignment::Center)
+                    .into()
+            }
+            NavigationLayout::Sidebar => {
+                // The sidebar goes to the left of the title and the page
+                let sidebar = self.get_navigation();
+
+                let mut page = column![];
+
+                if let Some(title) = self.get_title() {
+                    page = page.push(title);
+                }
+
+                if self.config.header_summary {
+                    page = page.push(Text::new(self.get_header_summary()));
+                }
+
+                let page = page
+                    .push(main_content)
+                    .width(iced::Length::Fill)
+                    .height(iced::Length::Fill)
+                    .spacing(20)
+                    .align_items(iced::Alignment::Center);
+
+                // Create the main window
+                row![sidebar, page]
+                    .width(iced::Length::Fill)
+                    .height(iced::Length::Fill)
+                    .padding(20)
+                    .spacing(20)
+                    .into()
+            }
+        };
+
+        // Ask before discarding the unsaved settings when leaving the settings page
+        let window_content = Modal::new(self.pending_page.is_some(), window_content, || {
+            let buttons = row![
+                button(Text::new("Discard")).on_press(ApplicationMessage::DiscardSettings),
+                button(Text::new("Keep editing"))
+                    .style(theme::Button::Secondary)
+                    .on_press(ApplicationMessage::KeepEditingSettings),
+            ]
+            .spacing(10);
+
+            Card::new(
+                Text::new("Unsaved settings"),
+                Text::new("Discard unsaved changes?"),
+            )
+            .foot(buttons)
+            .max_width(300)
             .into()
+        })
+        .backdrop(ApplicationMessage::KeepEditingSettings)
+        .on_esc(ApplicationMessage::KeepEditingSettings);
+
+        // Ask before closing the window, if enabled
+        let window_content = Modal::new(self.quit_requested, window_content, || {
+            let buttons = row![
+                button(Text::new("Quit")).on_press(ApplicationMessage::ConfirmQuit),
+                button(Text::new("Cancel"))
+                    .style(theme::Button::Secondary)
+                    .on_press(ApplicationMessage::CancelQuit),
+            ]
+            .spacing(10);
+
+            Card::new(Text::new("Quit"), Text::new("Close Icy System Monitor?"))
+                .foot(buttons)
+                .max_width(300)
+                .into()
+        })
+        .backdrop(ApplicationMessage::CancelQuit)
+        .on_esc(ApplicationMessage::CancelQuit);
+
+        // List the keyboard shortcuts over everything else
+        Modal::new(self.show_help, window_content, || {
+            let mut shortcuts_column = column![].spacing(10);
+
+            for shortcut in &SHORTCUTS {
+                shortcuts_column = shortcuts_column.push(
+                    row![
+                        Text::new(shortcut.keys)
+                            .font(MONOSPACE_FONT)
+                            .width(iced::Length::Units(60)),
+                        Text::new(shortcut.description),
+                    ]
+                    .spacing(10),
+                );
+            }
+
+            Card::new(Text::new("Keyboard shortcuts"), shortcuts_column)
+                .max_width(500)
+                .on_close(ApplicationMessage::ToggleHelp)
+                .into()
+        })
+        .backdrop(ApplicationMessage::ToggleHelp)
+        .on_esc(ApplicationMessage::ToggleHelp)
+        .into()
     }
 
     fn subscription(&self) -> iced::Subscription<ApplicationMessage> {
         // Send a message every second to update the system info in the update function
         // The update interval is stored in the config file
-        time::every(Duration::from_millis(self.config.update_interval))
-            .map(|_| ApplicationMessage::UpdateInfo)
+        let update_info = time::every(Duration::from_millis(self.get_update_interval()))
+            .map(|_| ApplicationMessage::UpdateInfo);
+
+        // Listen for the window being closed, so the session can be saved first,
+        // for the window gaining or losing focus, so the updates can be throttled,
+        // for the window being resized, so the divider on the home page keeps its share,
+        // and for the keyboard shortcuts
+        let window_events = subscription::events_with(|event, status| match event {
+            iced::Event::Window(iced::window::Event::CloseRequested) => {
+                Some(ApplicationMessage::CloseRequested)
+            }
+            iced::Event::Window(iced::window::Event::Focused) => {
+                Some(ApplicationMessage::FocusChanged(true))
+            }
+            iced::Event::Window(iced::window::Event::Unfocused) => {
+                Some(ApplicationMessage::FocusChanged(false))
+            }
+            iced::Event::Window(iced::window::Event::Resized { width, height }) => {
+                Some(ApplicationMessage::WindowResized(width, height))
+            }
+            iced::Event::Keyboard(event) => shortcuts::shortcut_message(event, status),
+            _ => None,
+        });
+
+        // Check the config file for changes made outside of the application
+        let config_watch =
+            time::every(CONFIG_WATCH_INTERVAL).map(|_| ApplicationMessage::CheckConfigFile);
+
+        let mut subscriptions = vec![update_info, window_events, config_watch];
+
+        // Refresh the temperature sensors on their own, slower, timer
+        if self.config.metrics.components {
+            subscriptions.push(
+                time::every(Duration::from_millis(
+                    self.config.temperature_interval.max(MIN_TIMER_INTERVAL),
+                ))
+                .map(|_| ApplicationMessage::RefreshTemps),
+            );
+        }
+
+        // Only ask for frames while the cpu bars are shown and moving, so that settled bars cost nothing
+        let shows_cpu_bars = matches!(self.page, MainWindowPage::Home)
+            && !self.meter_mode
+            && !self.config.compact_mode;
+
+        if shows_cpu_bars && self.is_animating() {
+            subscriptions.push(
+                time::every(ANIMATION_FRAME_INTERVAL).map(|_| ApplicationMessage::AnimationFrame),
+            );
+        }
+
+        // Write snapshots on their own, much slower, schedule
+        // In the events-only mode the events are written as they are detected instead
+        let export_config = &self.config.scheduled_export;
+        if export_config.enabled && export_config.mode == ExportMode::Continuous {
+            subscriptions.push(
+                time::every(Duration::from_secs(
+                    export_config.interval_minutes.max(1) * 60,
+                ))
+                .map(|_| ApplicationMessage::ScheduledExport),
+            );
+        }
+
+        Subscription::batch(subscriptions)
     }
 }
 
 /// The parts of the window that are shared between the pages
 impl ApplicationWindow {
-    /// Returns the header of the main window
+    /// Refreshes the categories of system info that are enabled in the config
     ///
-    /// This function returns the header of the main window which contains the title and the settings button
-    ///
-    /// # Example
-    ///
-    /// ```
+    /// Only the enabled categories are refreshed, instead of refreshing everything,
+    /// to keep the overhead of the application low.
+    /// The temperature sensors are refreshed separately, on a slower timer.
+    fn refresh_system_info(&mut self) {
+        let metrics = self.config.metrics;
+
+        if metrics.cpu {
+            self.sys.refresh_cpu();
+
+            // The per-cpu state is kept by index, so it would be misaligned
+            // if cpus were hot-plugged since the last update
+            if self.sys.cpus().len() != self.cpu_count {
+                self.reset_per_cpu_state();
+            }
+
+            self.update_max_cpu_frequencies();
+            self.scaling_governor = governor::read_scaling_governor();
+            self.update_cpu_times();
+            self.update_cpu_highlights();
+            self.update_cpu_peaks();
+
+            self.cpu_history
+                .push(self.sys.global_cpu_info().cpu_usage());
+
+            // The charts are paused while the system is busy
+            if self.config.show_core_chart && !self.is_reducing_work() {
+                if self.core_history.len() >= CORE_CHART_SAMPLES {
+                    self.core_history.pop_front();
+                }
+
+                // The samples are made safe to draw here, so that the chart of the average
+                // of many cpus is not thrown off by a reading that is not a number
+                self.core_history.push_back(
+                    self.sys
+                        .cpus()
+                        .iter()
+                        .map(|cpu| bar_percentage(cpu.cpu_usage()))
+                        .collect(),
+                );
+            }
+
+            // The load average is only reported on Unix-like platforms
+            #[cfg(unix)]
+            if self.config.show_load_average && !self.is_reducing_work() {
+                self.load_history.push(self.sys.load_average().one as f32);
+            }
+        }
+
+        if metrics.memory {
+            self.sys.refresh_memory();
+            self.numa_nodes = numa::get_numa_nodes();
+
+            let memory_usage = Metric::Memory.current_value(&self.sys).unwrap_or(0.0);
+            self.memory_highlight
+                .update(memory_usage, self.config.highlight_delta);
+            self.memory_history.push(memory_usage);
+            self.peak_memory_percentage = self.peak_memory_percentage.max(memory_usage);
+
+            self.update_swap_rate();
+            self.container_limits = cgroup::read_container_limits();
+        }
+
+        if metrics.disks {
+            self.sys.refresh_disks();
+        }
+
+        if metrics.networks {
+            self.sys.refresh_networks();
+        }
+
+        if metrics.processes {
+            self.sys.refresh_processes();
+        } else if let Ok(pid) = sysinfo::get_current_pid() {
+            // The usage of the monitor itself is shown on the settings page,
+            // even when the other processes are not refreshed
+            self.sys.refresh_process(pid);
+        }
+
+        let crossings = self.threshold_watcher.update(&self.sys, &self.config);
+
+        if !crossings.is_empty() {
+            let export_config = &self.config.scheduled_export;
+            if export_config.enabled && export_config.mode == ExportMode::EventsOnly {
+                self.write_threshold_events(&crossings);
+            }
+        }
+
+        // The sound is kept for the critical range, so that it stays a rare cue
+        let became_critical = self
+            .threshold_watcher
+            .update_critical(&self.sys, &self.config);
+
+        if became_critical && self.config.alert_sound {
+            self.play_alert_sound();
+        }
+    }
+
+    /// Schedules a save of the session, so that it is not lost if the application crashes
+    ///
+    /// The save is delayed, and skipped if the session changes again in the meantime.
+    fn schedule_session_save(&mut self) -> Command<ApplicationMessage> {
+        self.session_changes += 1;
+        let change_count = self.session_changes;
+
+        Command::perform(tokio::time::sleep(SESSION_SAVE_DELAY), move |_| {
+            ApplicationMessage::SaveSession(change_count)
+        })
+    }
+
+    /// Saves the session and closes the window
+    fn quit(&self) -> Command<ApplicationMessage> {
+        self.get_session_state().save();
+
+        iced::window::close()
+    }
+
+    /// Switches the main window to the page at `index`
+    fn select_page(&mut self, index: usize) {
+        match MainWindowPage::from_index(index, &self.config) {
+            Some(page) => self.page = page,
+            None => {
+                // TODO: Create a error page instead of printing the error
+                eprintln!("Invalid page index: {index}");
+                eprintln!("How did you even get here?");
+                eprintln!("Please report this bug on GitHub: https://github.com/DitherWither/icy-sysmonitor/issues")
+            }
+        }
+    }
+
+    /// Returns the interval in milliseconds between each update of the system info
+    ///
+    /// This is the update interval from the config, multiplied by the unfocused interval factor
+    /// while the window is not focused, if throttling is enabled in the config.
+    /// It is multiplied again while the system is busy, if reducing the work is enabled in the config.
+    pub fn get_update_interval(&self) -> u64 {
+        let mut interval = self.config.update_interval.max(MIN_TIMER_INTERVAL);
+
+        if self.config.throttle_when_unfocused && !self.is_focused {
+            interval *= self.config.unfocused_interval_factor.max(1);
+        }
+
+        if self.is_reducing_work() {
+            interval *= BUSY_INTERVAL_FACTOR;
+        }
+
+        interval
+    }
+
+    /// Returns whether the application is doing less work because the system is busy
+    ///
+    /// The average of the last minute is used instead of the current usage,
+    /// so that a short spike does not switch back and forth.
+    pub fn is_reducing_work(&self) -> bool {
+        self.config.reduce_work_when_busy
+            && self
+                .cpu_history
+                .average()
+                .map_or(false, |average| average > BUSY_CPU_THRESHOLD)
+    }
+
+    /// Returns whether the animated bars are still moving towards their values
+    fn is_animating(&self) -> bool {
+        if !self.config.animate_bars || self.is_reducing_work() {
+            return false;
+        }
+
+        let cpus = self.sys.cpus();
+
+        self.displayed_cpu_usage.len() != cpus.len()
+            || self
+                .displayed_cpu_usage
+                .iter()
+                .zip(cpus)
+                .any(|(displayed, cpu)| {
+                    (bar_percentage(cpu.cpu_usage()) - displayed).abs() > ANIMATION_SETTLE_DISTANCE
+                })
+    }
+
+    /// Moves the animated bars one frame towards the usage of the last update
+    ///
+    /// The bars ease out, so that they cover most of the distance within one update interval.
+    fn animate_bars(&mut self) {
+        let cpus = self.sys.cpus();
+
+        // Start from the current values when the number of cpus changes
+        if self.displayed_cpu_usage.len() != cpus.len() {
+            self.displayed_cpu_usage = cpus
+                .iter()
+                .map(|cpu| bar_percentage(cpu.cpu_usage()))
+                .collect();
+            return;
+        }
+
+        // The share of the remaining distance that is covered by each frame,
+        // chosen so that 95% of it is covered after one update interval
+        let frames_per_update =
+            self.get_update_interval() as f32 / ANIMATION_FRAME_INTERVAL.as_millis() as f32;
+        let step = 1.0 - 0.05_f32.powf(1.0 / frames_per_update.max(1.0));
+
+        for (displayed, cpu) in self.displayed_cpu_usage.iter_mut().zip(cpus) {
+            let target = bar_percentage(cpu.cpu_usage());
+
+            if (target - *displayed).abs() <= ANIMATION_SETTLE_DISTANCE {
+                *displayed = target;
+            } else {
+                *displayed += (target - *displayed) * step;
+            }
+        }
+    }
+
+    /// Returns the usage of a cpu as it should be drawn, which follows the animation if it is enabled
+    pub fn get_displayed_cpu_usage(&self, index: usize, cpu_usage: f32) -> f32 {
+        if !self.config.animate_bars || self.is_reducing_work() {
+            return cpu_usage;
+        }
+
+        self.displayed_cpu_usage
+            .get(index)
+            .copied()
+            .unwrap_or(cpu_usage)
+    }
+
+    /// Clears the state that is kept for each cpu, and records the new number of cpus
+    ///
+    /// This function should be called when the number of cpus changes, for example when
+    /// cpus are hot-plugged on a virtual machine. The state is rebuilt on the following updates.
+    fn reset_per_cpu_state(&mut self) {
+        self.cpu_count = self.sys.cpus().len();
+
+        self.max_cpu_frequencies.clear();
+        self.cpu_highlights.clear();
+        self.cpu_peaks.clear();
+        self.cpu_times_breakdown.clear();
+        self.core_history.clear();
+        self.displayed_cpu_usage.clear();
+    }
+
+    /// Updates the highest frequency observed for each cpu
+    ///
+    /// This function should be called after the system info is refreshed.
+    fn update_max_cpu_frequencies(&mut self) {
+        let frequencies: Vec<u64> = self.sys.cpus().iter().map(|cpu| cpu.frequency()).collect();
+
+        update_per_cpu(
+            &mut self.max_cpu_frequencies,
+            &frequencies,
+            0,
+            |max_frequency, frequency| *max_frequency = (*max_frequency).max(frequency),
+        );
+    }
+
+    /// Updates the highlight state of each cpu's usage
+    ///
+    /// This function should be called after the cpu info is refreshed.
+    fn update_cpu_highlights(&mut self) {
+        let usages: Vec<f32> = self.sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+        let highlight_delta = self.config.highlight_delta;
+
+        update_per_cpu(
+            &mut self.cpu_highlights,
+            &usages,
+            ChangeHighlight::default(),
+            |highlight, usage| highlight.update(usage, highlight_delta),
+        );
+    }
+
+    /// Updates the recent peak usage of each cpu
+    ///
+    /// A new peak is taken right away, and a peak above the usage falls back
+    /// at a fixed rate, so that short bursts stay visible for a few seconds.
+    /// This function should be called after the cpu info is refreshed.
+    fn update_cpu_peaks(&mut self) {
+        let decay = CPU_PEAK_DECAY * self.get_update_interval() as f32 / 1000.0;
+        let usages: Vec<f32> = self.sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+
+        update_per_cpu(&mut self.cpu_peaks, &usages, 0.0, |peak, usage| {
+            *peak = (*peak - decay).max(bar_percentage(usage))
+        });
+    }
+
+    /// Reads the cpu times and computes how they changed since the previous update
+    ///
+    /// The breakdown is cleared if the cpu times could not be read, or if the number
+    /// of cpus changed since the previous update.
+    fn update_cpu_times(&mut self) {
+        let current_times = cpu_times::read_cpu_times().unwrap_or_default();
+
+        self.cpu_times_breakdown = if current_times.len() == self.cpu_times.len() {
+            self.cpu_times
+                .iter()
+                .zip(&current_times)
+                .map(|(previous, current)| {
+                    CpuTimesBreakdown::between(previous, current).unwrap_or_default()
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self.cpu_times = current_times;
+    }
+
+    /// Reads the swap counters and computes the swap rate since the previous update
+    ///
+    /// The rate is cleared if the swap counters could not be read.
+    fn update_swap_rate(&mut self) {
+        let current =
+            swap_activity::read_swap_counters().map(|counters| (counters, Instant::now()));
+
+        self.swap_rate = match (&self.swap_counters, &current) {
+            (Some((previous, previous_time)), Some((current, current_time))) => SwapRate::between(
+                previous,
+                current,
+                current_time.duration_since(*previous_time),
+            ),
+            _ => None,
+        };
+
+        self.swap_counters = current;
+    }
+
+    /// Writes a snapshot of the system info to the export directory
+    ///
+    /// Errors are printed and otherwise ignored, so a failed export never stops the application.
+    fn write_scheduled_export(&self) {
+        let directory = match self.get_export_dir() {
+            Some(directory) => directory,
+            None => {
+                eprintln!("Could not find a directory to write the snapshot to");
+                return;
+            }
+        };
+
+        if let Err(error) = export::write_snapshot(&self.sys, &directory) {
+            eprintln!("Could not write the snapshot: {error}");
+            eprintln!("Please check the permissions of {}", directory.display());
+        }
+    }
+
+    /// Appends the metrics that went above their thresholds since the previous update
+    /// to the threshold event log in the export directory
+    ///
+    /// Errors are printed and otherwise ignored, so a failed export never stops the application.
+    fn write_threshold_events(&self, crossings: &[ThresholdCrossing]) {
+        let directory = match self.get_export_dir() {
+            Some(directory) => directory,
+            None => {
+                eprintln!("Could not find a directory to write the threshold events to");
+                return;
+            }
+        };
+
+        if let Err(error) = export::append_threshold_events(crossings, &directory) {
+            eprintln!("Could not write the threshold events: {error}");
+            eprintln!("Please check the permissions of {}", directory.display());
+        }
+    }
+
+    /// Plays the alert sound, unless it was already played within the cooldown
+    fn play_alert_sound(&mut self) {
+        let now = Instant::now();
+
+        if let Some(last_played) = self.alert_sound_played {
+            if now.duration_since(last_played) < ALERT_SOUND_COOLDOWN {
+                return;
+            }
+        }
+
+        alert_sound::play();
+        self.alert_sound_played = Some(now);
+    }
+
+    /// Returns the directory that exports are written to
+    ///
+    /// This is the directory set in the config, or the default export directory if none is set.
+    fn get_export_dir(&self) -> Option<std::path::PathBuf> {
+        match &self.config.scheduled_export.directory {
+            Some(directory) => Some(directory.clone()),
+            None => export::default_export_dir(),
+        }
+    }
+
+    /// Returns a short one-line summary of the current cpu and memory usage
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let summary = self.get_metrics_summary();
+    /// // CPU 42% | RAM 63%
+    /// ```
+    pub fn get_metrics_summary(&self) -> String {
+        let cpu_usage = Metric::Cpu.current_value(&self.sys).unwrap_or(0.0);
+        let memory_usage = Metric::Memory.current_value(&self.sys).unwrap_or(0.0);
+
+        format!("CPU {cpu_usage:.0}% | RAM {memory_usage:.0}%")
+    }
+
+    /// Returns the summary shown in the header, which adds the network traffic to the metrics summary
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let summary = self.get_header_summary();
+    /// // CPU 42% | RAM 63% | ↓ 2.1 MB/s
+    /// ```
+    fn get_header_summary(&self) -> String {
+        let mut summary = self.get_metrics_summary();
+
+        if self.config.metrics.networks {
+            let (received, _) = self.get_network_rates();
+            summary += &format!(
+                " | ↓ {}",
+                format_rate(received, self.config.network_bits, false)
+            );
+        }
+
+        summary
+    }
+
+    /// Returns the cpu usage and memory of the monitor itself
+    ///
+    /// This lets users check how lightweight the monitor is, for example with
+    /// the options that reduce its work. Returns `None` if the process could not be found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let own_usage = self.get_own_usage();
+    /// // Some("icy-sysmonitor uses 0.8% cpu and 42.3 MB of memory")
+    /// ```
+    pub fn get_own_usage(&self) -> Option<String> {
+        let pid = sysinfo::get_current_pid().ok()?;
+        let process = self.sys.process(pid)?;
+
+        Some(format!(
+            "icy-sysmonitor uses {:.1}% cpu and {} of memory",
+            process.cpu_usage(),
+            self.config.size_unit.format(process.memory())
+        ))
+    }
+
+    /// Returns the bytes received and transmitted per second over all network interfaces
+    ///
+    /// sysinfo reports the bytes transferred since the previous refresh,
+    /// so they are divided by the update interval to get a rate.
+    pub fn get_network_rates(&self) -> (f64, f64) {
+        let (received, transmitted) =
+            self.sys
+                .networks()
+                .iter()
+                .fold((0, 0), |(received, transmitted), (_, data)| {
+                    (received + data.received(), transmitted + data.transmitted())
+                });
+
+        let interval_seconds = (self.get_update_interval() as f64 / 1000.0).max(0.001);
+
+        (
+            received as f64 / interval_seconds,
+            transmitted as f64 / interval_seconds,
+        )
+    }
+
+    /// Returns the height that the share of the divider on the home page is relative to, in pixels
+    ///
+    /// The sections share the window with the header and the padding, which depend on
+    /// the layout, so the divider is placed relative to the whole window instead.
+    /// Converting both ways against the same height keeps the divider where it was dropped,
+    /// and gives each section the same share of the window after a resize.
+    pub fn get_home_split_height(&self) -> u32 {
+        self.window_size.1.max(1)
+    }
+
+    /// Returns the state of the user interface that should be restored on the next launch
+    fn get_session_state(&self) -> SessionState {
+        SessionState {
+            page: self.page.to_index(),
+        }
+    }
+
+    /// Returns the header of the main window
+    ///
+    /// This function returns the header of the main window which contains the title and the tab bar.
+    /// The tab bar is left out when it is placed at the bottom of the window.
+    ///
+    /// # Example
+    ///
+    /// ```
     /// // Get the header
     /// let header = self.get_header();
-    /// // Roughly looks like:
+    /// // Roughly looks like this with the tab bar beside the title:
     /// //                      ------- -----------
     /// // Icy System Monitor   |Home | |Settings |
     /// //                      ------- -----------
     /// ```
     fn get_header(&self) -> iced::Element<ApplicationMessage> {
-        // Create the title
-        let title = Text::new("Icy System Monitor").size(50);
-
-        // Create the tab bar for the pages
-        let tab_bar = TabBar::new(self.page.to_index(), ApplicationMessage::TabSelected)
-            .push(TabLabel::IconText(Icon::House.into(), "Home".to_string()))
-            .push(TabLabel::IconText(
-                Icon::Gear.into(),
-                "Settings".to_string(),
-            ));
+        let title = self.get_title();
+
+        let header: iced::Element<_> = match self.config.tab_bar_position {
+            TabBarPosition::Top => {
+                let mut header = column![].spacing(20);
+
+                // Add the title, unless it is hidden to save space
+                if let Some(title) = title {
+                    header = header.push(title);
+                }
+
+                header.push(self.get_navigation_row()).into()
+            }
+            TabBarPosition::BesideTitle => {
+                let mut header = row![].spacing(20).align_items(iced::Alignment::Center);
+
+                if let Some(title) = title {
+                    header = header.push(title);
+                }
+
+                header.push(self.get_navigation_row()).into()
+            }
+            TabBarPosition::Bottom => {
+                let mut header = column![];
+
+                if let Some(title) = title {
+                    header = header.push(title);
+                }
+
+                header.into()
+            }
+        };
+
         // Create the header
-        column![title, tab_bar]
+        container(header)
             .width(iced::Length::Fill)
             .height(iced::Length::Shrink)
             .padding(20)
-            .spacing(20)
             .into()
     }
+
+    /// Returns the navigation, with the usage summary next to it if it is enabled
+    fn get_navigation_row(&self) -> iced::Element<ApplicationMessage> {
+        // Create the navigation for the pages
+        let navigation = self.get_navigation();
+
+        // Keep the key numbers visible next to the navigation, if enabled
+        if self.config.header_summary {
+            row![navigation, Text::new(self.get_header_summary())]
+                .spacing(20)
+                .align_items(iced::Alignment::Center)
+                .into()
+        } else {
+            navigation
+        }
+    }
+
+    /// Returns the title of the main window, with the cpu model name below it
+    ///
+    /// Both the title and the cpu model name can be hidden in the config.
+    /// Returns `None` if both are hidden.
+    fn get_title(&self) -> Option<iced::Element<ApplicationMessage>> {
+        let mut title = column![].spacing(5);
+        let mut is_empty = true;
+
+        if self.config.show_title {
+            title = title.push(Text::new("Icy System Monitor").size(50));
+            is_empty = false;
+        }
+
+        if self.config.show_cpu_brand && !self.cpu_brand.is_empty() {
+            title = title.push(Text::new(&self.cpu_brand).size(20));
+            is_empty = false;
+        }
+
+        if is_empty {
+            None
+        } else {
+            Some(title.into())
+        }
+    }
+
+    /// Returns the page navigation of the main window
+    ///
+    /// This function returns either a tab bar or a sidebar, depending on the
+    /// navigation layout stored in the config.
+    fn get_navigation(&self) -> iced::Element<ApplicationMessage> {
+        match self.config.navigation {
+            NavigationLayout::TabBar => self.get_tab_bar(),
+            NavigationLayout::Sidebar => self.get_sidebar(),
+        }
+    }
+
+    /// Returns the tab bar used to switch between the pages
+    fn get_tab_bar(&self) -> iced::Element<ApplicationMessage> {
+        let mut tab_bar = TabBar::new(self.page.to_index(), ApplicationMessage::TabSelected);
+
+        for (icon, label) in MainWindowPage::LABELS {
+            let tab_label = if self.config.navigation_icons {
+                TabLabel::IconText(icon.into(), label.to_string())
+            } else {
+                TabLabel::Text(label.to_string())
+            };
+
+            tab_bar = tab_bar.push(tab_label);
+        }
+
+        tab_bar.into()
+    }
+
+    /// Returns the vertical sidebar used to switch between the pages
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sidebar = self.get_sidebar();
+    /// // Roughly looks like:
+    /// // [# Home    ]
+    /// // [* Settings]
+    /// ```
+    fn get_sidebar(&self) -> iced::Element<ApplicationMessage> {
+        let current_index = self.page.to_index();
+
+        let mut sidebar = column![].spacing(10).width(iced::Length::Units(160));
+
+        for (index, (icon, label)) in MainWindowPage::LABELS.into_iter().enumerate() {
+            // Highlight the button of the page that is currently open
+            let style = if index == current_index {
+                theme::Button::Primary
+            } else {
+                theme::Button::Secondary
+            };
+
+            let mut button_content = row![].spacing(10);
+
+            if self.config.navigation_icons {
+                button_content =
+                    button_content.push(Text::new(char::from(icon).to_string()).font(ICON_FONT));
+            }
+
+            let page_button = button(button_content.push(Text::new(label)))
+                .width(iced::Length::Fill)
+                .style(style)
+                .on_press(ApplicationMessage::TabSelected(index));
+
+            sidebar = sidebar.push(page_button);
+        }
+
+        sidebar.into()
+    }
+}
+
+/// Shows the values of a new config on the settings page, if it is open without unsaved changes
+///
+/// The settings page shows the values of the config it was opened with. Unsaved changes
+/// are kept, so that they are not lost without the prompt. A page without changes is rebuilt,
+/// as it would otherwise no longer match the config, and look like it has changes.
+fn refresh_clean_settings_page(
+    page: &mut MainWindowPage,
+    current_config: &config::Config,
+    new_config: &config::Config,
+) {
+    if let MainWindowPage::Settings(state) = page {
+        if !state.is_dirty(current_config) {
+            *state = SettingsState::new(new_config);
+        }
+    }
+}
+
+/// Updates the state that is kept for each cpu with a new reading of every cpu
+///
+/// The state is resized to the number of readings first, so that a changing number of cpus
+/// never panics: new cpus start from `initial`, and the state of removed cpus is dropped.
+///
+/// # Example
+///
+/// ```
+/// let mut max_frequencies = vec![2000, 3000];
+/// update_per_cpu(&mut max_frequencies, &[2500, 1000, 1500], 0, |max, frequency| {
+///     *max = (*max).max(frequency)
+/// });
+/// // max_frequencies is now [2500, 3000, 1500]
+/// ```
+fn update_per_cpu<T: Clone, V: Copy>(
+    state: &mut Vec<T>,
+    readings: &[V],
+    initial: T,
+    mut update: impl FnMut(&mut T, V),
+) {
+    state.resize(readings.len(), initial);
+
+    for (entry, reading) in state.iter_mut().zip(readings) {
+        update(entry, *reading);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the settings page's state, for a page that is known to be the settings page
+    fn settings_state(page: &MainWindowPage) -> &SettingsState {
+        match page {
+            MainWindowPage::Settings(state) => state,
+            _ => panic!("The settings page is not open"),
+        }
+    }
+
+    #[test]
+    fn reloaded_update_interval_keeps_a_clean_settings_page_clean() {
+        let config = config::Config::default();
+        let mut page = MainWindowPage::Settings(SettingsState::new(&config));
+
+        let mut reloaded = config.clone();
+        reloaded.update_interval = 2500;
+
+        refresh_clean_settings_page(&mut page, &config, &reloaded);
+
+        assert!(!settings_state(&page).is_dirty(&reloaded));
+    }
+
+    #[test]
+    fn reloaded_update_interval_keeps_unsaved_changes() {
+        let mut config = config::Config::default();
+        let mut state = SettingsState::new(&config);
+        let _ = state.update(
+            &SettingsMessage::UpdateIntervalText("3".to_string()),
+            &mut config,
+        );
+        let mut page = MainWindowPage::Settings(state);
+
+        let mut reloaded = config.clone();
+        reloaded.update_interval = 2500;
+
+        refresh_clean_settings_page(&mut page, &config, &reloaded);
+
+        // The typed interval is still there, so the page still differs from the config
+        assert!(settings_state(&page).is_dirty(&reloaded));
+    }
+
+    fn keep_max(max: &mut u64, value: u64) {
+        *max = (*max).max(value);
+    }
+
+    #[test]
+    fn update_per_cpu_adds_state_for_new_cpus() {
+        let mut state = vec![2000, 3000];
+
+        update_per_cpu(&mut state, &[2500, 1000, 1500, 800], 0, keep_max);
+
+        assert_eq!(state, vec![2500, 3000, 1500, 800]);
+    }
+
+    #[test]
+    fn update_per_cpu_drops_state_of_removed_cpus() {
+        let mut state = vec![2000, 3000, 4000];
+
+        update_per_cpu(&mut state, &[1000], 0, keep_max);
+
+        assert_eq!(state, vec![2000]);
+    }
+
+    #[test]
+    fn update_per_cpu_follows_the_cpu_count_up_and_down() {
+        let mut state = Vec::new();
+
+        update_per_cpu(&mut state, &[10, 20], 0, keep_max);
+        update_per_cpu(&mut state, &[5, 30, 40, 50], 0, keep_max);
+        assert_eq!(state, vec![10, 30, 40, 50]);
+
+        update_per_cpu(&mut state, &[60, 10], 0, keep_max);
+        assert_eq!(state, vec![60, 30]);
+
+        // A cpu that comes back starts over from the initial state
+        update_per_cpu(&mut state, &[0, 0, 5], 0, keep_max);
+        assert_eq!(state, vec![60, 30, 5]);
+    }
 }